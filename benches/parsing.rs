@@ -1,5 +1,5 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use num_rational::Rational32;
+use num_rational::{Ratio, Rational32, Rational64};
 use num_rational_parse::RationalParse;
 
 fn bench_parse(c: &mut Criterion) {
@@ -28,5 +28,44 @@ fn bench_parse(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_parse);
+fn bench_reject(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reject_flex");
+
+    group.bench_function("hex", |b| {
+        b.iter(|| Rational32::from_str_flex(black_box("0x10")))
+    });
+
+    group.bench_function("unicode", |b| {
+        b.iter(|| Rational32::from_str_flex(black_box("³/²")))
+    });
+
+    let garbage = "1".repeat(1_000) + "x";
+    group.bench_function("long_garbage", |b| {
+        b.iter(|| Rational32::from_str_flex(black_box(&garbage)))
+    });
+
+    group.finish();
+}
+
+fn bench_long_digits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("long_digits");
+
+    group.bench_function("integer", |b| {
+        b.iter(|| Rational64::from_str_flex(black_box("1234567890123456789")))
+    });
+
+    group.bench_function("fraction", |b| {
+        b.iter(|| {
+            Ratio::<i128>::from_str_flex(black_box("12345678901234567890/98765432109876543210"))
+        })
+    });
+
+    group.bench_function("long_fraction", |b| {
+        b.iter(|| Ratio::<i128>::from_str_flex(black_box("0.1234567890123456789012345678901234")))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_reject, bench_long_digits);
 criterion_main!(benches);
@@ -0,0 +1,318 @@
+//! Helpers for parsing many rational strings at once.
+
+use crate::{
+    FlexInteger, ParseRatioError, RatioErrorKind, RationalParse, parse_flex_widest, validate_flex,
+};
+use num_rational::Ratio;
+use num_traits::{Bounded, ToPrimitive};
+use std::cmp::Ordering;
+use std::io::BufRead;
+#[cfg(feature = "std")]
+use std::io::Read;
+
+/// Checks that every item is a valid rational number for the integer type `T`.
+///
+/// Validation stops at the first invalid item, whose index and error are
+/// returned. Values are not reduced (see [`validate_flex`]), so this is a
+/// cheap precondition check before committing a batch of inputs.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational_parse::{RatioErrorKind, validate_all};
+///
+/// assert!(validate_all::<i32>(&["1/2", "0.75", "3e2"]).is_ok());
+///
+/// let (index, err) = validate_all::<i32>(&["1/2", "1/0", "x"]).unwrap_err();
+/// assert_eq!(index, 1);
+/// assert_eq!(*err.kind(), RatioErrorKind::ZeroDenominator);
+/// ```
+pub fn validate_all<T: FlexInteger>(items: &[&str]) -> Result<(), (usize, ParseRatioError)> {
+    items
+        .iter()
+        .enumerate()
+        .try_for_each(|(i, s)| validate_flex::<T>(s).map_err(|e| (i, e)))
+}
+
+/// The largest magnitudes seen while parsing a batch, as returned by [`ratio_stats`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RatioStats {
+    /// The number of values parsed.
+    pub count: usize,
+    /// The largest absolute value of a reduced numerator.
+    pub max_numer: u128,
+    /// The largest reduced denominator.
+    pub max_denom: u128,
+}
+
+impl RatioStats {
+    /// Returns `true` if every value seen can be stored in a `Ratio<T>`.
+    ///
+    /// This is conservative: a numerator equal to `T::MIN` in magnitude is
+    /// reported as not fitting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_rational_parse::ratio_stats;
+    ///
+    /// let stats = ratio_stats(["1/3", "-300"]).unwrap();
+    /// assert!(stats.fits::<i16>());
+    /// assert!(!stats.fits::<i8>());
+    /// ```
+    pub fn fits<T: Bounded + ToPrimitive>(&self) -> bool {
+        let max = T::max_value().to_u128().unwrap_or(u128::MAX);
+        self.max_numer <= max && self.max_denom <= max
+    }
+}
+
+/// Parses every item as a `Ratio<i128>` and reports the largest numerator and
+/// denominator encountered.
+///
+/// This helps to pick the smallest backing integer type for a data set. Items
+/// are parsed with [`parse_flex_widest`], so values are reduced before they are
+/// measured. Parsing stops at the first invalid item, whose index and error are
+/// returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational_parse::ratio_stats;
+///
+/// let stats = ratio_stats(["0.5", "-1234/10", "3e4"]).unwrap();
+/// assert_eq!(stats.count, 3);
+/// assert_eq!(stats.max_numer, 30000);
+/// assert_eq!(stats.max_denom, 5);
+/// assert!(stats.fits::<i32>());
+/// ```
+pub fn ratio_stats<I>(items: I) -> Result<RatioStats, (usize, ParseRatioError)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .try_fold(RatioStats::default(), |stats, (i, s)| {
+            let r = parse_flex_widest(s.as_ref()).map_err(|e| (i, e))?;
+            Ok(RatioStats {
+                count: stats.count + 1,
+                max_numer: stats.max_numer.max(r.numer().unsigned_abs()),
+                max_denom: stats.max_denom.max(r.denom().unsigned_abs()),
+            })
+        })
+}
+
+/// Parses every item and returns the smallest value, or `None` if there are no
+/// items.
+///
+/// Items are parsed one at a time with [`RationalParse::from_str_flex`] and
+/// compared by value, so `"0.5"` and `"1/2"` are equal. Parsing stops at the
+/// first invalid item, whose index and error are returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::parse_flex_min;
+///
+/// let min = parse_flex_min::<i32, _>(["0.5", "-1/3", "2e1"]).unwrap();
+/// assert_eq!(min, Some(Ratio::new(-1, 3)));
+/// ```
+pub fn parse_flex_min<T, I>(items: I) -> Result<Option<Ratio<T>>, (usize, ParseRatioError)>
+where
+    T: FlexInteger,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    extremum(items, Ordering::Less)
+}
+
+/// Parses every item and returns the largest value, or `None` if there are no
+/// items.
+///
+/// This is the counterpart of [`parse_flex_min`], with the same parsing and
+/// error reporting.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::parse_flex_max;
+///
+/// let max = parse_flex_max::<i32, _>(["0.5", "-1/3", "2e1"]).unwrap();
+/// assert_eq!(max, Some(Ratio::new(20, 1)));
+///
+/// let (index, _) = parse_flex_max::<i32, _>(["1", "x", "2"]).unwrap_err();
+/// assert_eq!(index, 1);
+/// ```
+pub fn parse_flex_max<T, I>(items: I) -> Result<Option<Ratio<T>>, (usize, ParseRatioError)>
+where
+    T: FlexInteger,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    extremum(items, Ordering::Greater)
+}
+
+/// Returns the parsed item that compares as `wanted` to all others.
+fn extremum<T, I>(items: I, wanted: Ordering) -> Result<Option<Ratio<T>>, (usize, ParseRatioError)>
+where
+    T: FlexInteger,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .try_fold(None, |best: Option<Ratio<T>>, (i, s)| {
+            let r = Ratio::<T>::from_str_flex(s.as_ref()).map_err(|e| (i, e))?;
+            Ok(Some(match best {
+                Some(best) if best.cmp(&r) != wanted.reverse() => best,
+                _ => r,
+            }))
+        })
+}
+
+/// Parses every whitespace-separated token of `s`, yielding a result for each.
+///
+/// Tokens are split on ASCII whitespace and parsed lazily with
+/// [`RationalParse::from_str_flex`], as in a line of a data file like
+/// `"1/2 0.75 -3e2"`.
+///
+/// Whitespace always ends a token here, even where the grammar of a single
+/// number would allow it: `"3 / 2"` is read as the three tokens `"3"`, `"/"`
+/// and `"2"`, of which only the `"/"` fails, with
+/// [`RatioErrorKind::ParseError`]. Fractions must therefore be written without
+/// spaces around the `/`. Parse a single number with
+/// [`RationalParse::from_str_flex`] to accept them.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::parse_whitespace_separated;
+///
+/// let values: Vec<_> = parse_whitespace_separated::<i32>(" 1/2\t0.75 -3e2\n")
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(values, [Ratio::new(1, 2), Ratio::new(3, 4), Ratio::from_integer(-300)]);
+///
+/// let results: Vec<_> = parse_whitespace_separated::<i32>("3 / 2").collect();
+/// assert_eq!(results.len(), 3);
+/// assert!(results[1].is_err());
+/// ```
+pub fn parse_whitespace_separated<'a, T: FlexInteger + 'a>(
+    s: &'a str,
+) -> impl Iterator<Item = Result<Ratio<T>, ParseRatioError>> + 'a {
+    s.split_ascii_whitespace().map(Ratio::<T>::from_str_flex)
+}
+
+/// Parses one column of comma-separated rows, yielding a result for every row.
+///
+/// Rows are read lazily from `reader`, one line at a time, and split on `,`.
+/// The field at index `column` is parsed with [`RationalParse::from_str_flex`],
+/// which ignores whitespace around it. With `has_header`, the first line is
+/// skipped without being looked at.
+///
+/// A row without the requested column, including an empty line, yields
+/// [`RatioErrorKind::ParseError`], and the following rows are still parsed. A
+/// line that cannot be read, because of an I/O error or invalid UTF-8, also
+/// yields [`RatioErrorKind::ParseError`], after which the iterator ends.
+///
+/// Quoting and escaping are not supported: numbers never need them, but a
+/// quoted field containing a comma elsewhere in the row shifts the columns
+/// after it.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, parse_csv_column};
+///
+/// let csv = "name,ratio\nhalf,1/2\nthird, 0.25 \nbroken\n";
+/// let results: Vec<_> = parse_csv_column::<i32, _>(csv.as_bytes(), 1, true).collect();
+/// assert_eq!(results[0], Ok(Ratio::new(1, 2)));
+/// assert_eq!(results[1], Ok(Ratio::new(1, 4)));
+/// assert_eq!(*results[2].as_ref().unwrap_err().kind(), RatioErrorKind::ParseError);
+/// ```
+pub fn parse_csv_column<T, R>(
+    reader: R,
+    column: usize,
+    has_header: bool,
+) -> impl Iterator<Item = Result<Ratio<T>, ParseRatioError>>
+where
+    T: FlexInteger,
+    R: BufRead,
+{
+    let mut lines = reader.lines().skip(usize::from(has_header));
+    let mut failed = false;
+    std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        let row = match lines.next()? {
+            Ok(row) => row,
+            // A reader may fail again on every call, so stop after the first error.
+            Err(_) => {
+                failed = true;
+                return Some(Err(ParseRatioError::new(RatioErrorKind::ParseError)));
+            }
+        };
+        Some(match row.split(',').nth(column) {
+            Some(field) => Ratio::<T>::from_str_flex(field),
+            None => Err(ParseRatioError::new(RatioErrorKind::ParseError)),
+        })
+    })
+}
+
+/// The largest payload accepted by [`from_length_prefixed`], in bytes.
+///
+/// This bounds the allocation for a corrupt or hostile length prefix. It is
+/// far more than any rational number string needs.
+#[cfg(feature = "std")]
+pub const MAX_PREFIXED_LEN: u32 = 4096;
+
+/// Reads one length-prefixed rational number string from `reader` and parses
+/// it with [`from_bytes_flex`](crate::from_bytes_flex).
+///
+/// The record is a big-endian `u32` byte length, followed by that many bytes
+/// of UTF-8 text. Exactly one record is consumed, so a reader over a stream of
+/// records can be passed again for the next one.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if the record is truncated or cannot
+/// be read, if the text is not valid UTF-8, or if the length exceeds
+/// [`MAX_PREFIXED_LEN`]. An oversized payload is not read, so the reader is
+/// left in the middle of the record. Otherwise, the errors of
+/// [`RationalParse::from_str_flex`] are returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_length_prefixed;
+///
+/// let mut records: &[u8] = b"\0\0\0\x04-3/4\0\0\0\x031e2";
+/// assert_eq!(from_length_prefixed::<i32, _>(&mut records).unwrap(), Ratio::new(-3, 4));
+/// assert_eq!(from_length_prefixed::<i32, _>(&mut records).unwrap(), Ratio::from_integer(100));
+/// assert!(from_length_prefixed::<i32, _>(&mut records).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn from_length_prefixed<T, R>(reader: &mut R) -> Result<Ratio<T>, ParseRatioError>
+where
+    T: FlexInteger,
+    R: Read,
+{
+    let error = || ParseRatioError::new(RatioErrorKind::ParseError);
+    let mut prefix = [0; 4];
+    reader.read_exact(&mut prefix).map_err(|_| error())?;
+    let len = u32::from_be_bytes(prefix);
+    if len > MAX_PREFIXED_LEN {
+        return Err(error());
+    }
+    let mut payload = vec![0; len as usize];
+    reader.read_exact(&mut payload).map_err(|_| error())?;
+    crate::from_bytes_flex(&payload)
+}
@@ -0,0 +1,81 @@
+//! Memoized parsing of recurring inputs, enabled by the `cache` feature.
+
+use crate::{FlexInteger, ParseRatioError, RationalParse};
+use lru::LruCache;
+use num_rational::Ratio;
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard};
+
+/// A parser that remembers the results for the most recently used inputs.
+///
+/// This trades memory for speed when the same strings are parsed over and
+/// over. Results are identical to [`RationalParse::from_str_flex`], and errors
+/// are cached as well. When the cache is full, the least recently used entry is
+/// evicted. The cache is behind a lock, so a `CachedParser` can be shared
+/// between threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::num::NonZeroUsize;
+/// use num_rational::Ratio;
+/// use num_rational_parse::CachedParser;
+///
+/// let parser = CachedParser::<i32>::new(NonZeroUsize::new(128).unwrap());
+/// assert_eq!(parser.parse("3/4").unwrap(), Ratio::new(3, 4));
+/// assert_eq!(parser.parse("3/4").unwrap(), Ratio::new(3, 4));
+/// assert_eq!(parser.len(), 1);
+/// ```
+pub struct CachedParser<T> {
+    cache: Mutex<LruCache<String, Result<Ratio<T>, ParseRatioError>>>,
+}
+
+impl<T: FlexInteger> CachedParser<T> {
+    /// Creates a parser that caches up to `capacity` inputs.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        CachedParser {
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Parses a string like [`RationalParse::from_str_flex`], reusing the
+    /// cached result if the same string was parsed recently.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`RationalParse::from_str_flex`].
+    pub fn parse(&self, s: &str) -> Result<Ratio<T>, ParseRatioError> {
+        if let Some(result) = self.lock().get(s) {
+            return result.clone();
+        }
+        // Parse without holding the lock, so that other threads are not blocked.
+        let result = Ratio::<T>::from_str_flex(s);
+        self.lock().put(s.to_owned(), result.clone());
+        result
+    }
+
+    /// Returns the number of cached inputs.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no inputs are cached.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the maximum number of cached inputs.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.lock().cap()
+    }
+
+    /// Removes all cached inputs.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LruCache<String, Result<Ratio<T>, ParseRatioError>>> {
+        // The cache is always in a consistent state, even if a thread panicked.
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
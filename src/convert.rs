@@ -0,0 +1,343 @@
+//! Conversions between ratios and other numeric representations.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind, RationalParse};
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{CheckedMul, CheckedSub, NumCast, One, ToPrimitive, Zero};
+use std::str::FromStr;
+
+/// Converts a ratio to a ratio over a different integer type.
+///
+/// This is typically used to widen a parsed `Ratio<i32>` to `Ratio<i64>`, which
+/// always succeeds. Narrowing conversions are checked: `None` is returned if the
+/// numerator or denominator does not fit in `U`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::widen;
+///
+/// let r = Ratio::<i32>::new(3, 4);
+/// assert_eq!(widen::<i32, i64>(r), Some(Ratio::new(3, 4)));
+///
+/// let big = Ratio::<i64>::new(1, 1 << 40);
+/// assert_eq!(widen::<i64, i32>(big), None);
+/// ```
+pub fn widen<T, U>(r: Ratio<T>) -> Option<Ratio<U>>
+where
+    T: Clone + ToPrimitive,
+    U: Clone + NumCast,
+{
+    let (numer, denom) = r.into_raw();
+    // The input is already reduced with a positive denominator, and a lossless
+    // conversion preserves both properties.
+    Some(Ratio::new_raw(U::from(numer)?, U::from(denom)?))
+}
+
+/// Creates a reduced ratio from a numerator and denominator.
+///
+/// This is the numeric counterpart of the string parsers: unlike
+/// [`Ratio::new`], it reports a zero denominator or an overflow as a
+/// [`ParseRatioError`] instead of panicking, so numeric and string inputs share
+/// one error type.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ZeroDenominator`] if `denom` is zero, and
+/// [`RatioErrorKind::Overflow`] if the reduced value does not fit in `T` with
+/// a positive denominator, as with `(i32::MIN, -1)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, ratio_from_parts};
+///
+/// assert_eq!(ratio_from_parts(6, -4).unwrap(), Ratio::new(-3, 2));
+/// assert_eq!(
+///     *ratio_from_parts(1, 0).unwrap_err().kind(),
+///     RatioErrorKind::ZeroDenominator
+/// );
+/// ```
+pub fn ratio_from_parts<T: Clone + Integer + CheckedSub>(
+    numer: T,
+    denom: T,
+) -> Result<Ratio<T>, ParseRatioError> {
+    if denom.is_zero() {
+        return Err(RatioErrorKind::ZeroDenominator.into());
+    }
+    // `Ratio::new` overflows where the gcd or the sign flip does not fit in
+    // `T`, so both are checked here. The gcd only overflows if both parts are
+    // zero or the most negative value, which the first two cases cover.
+    if numer.is_zero() {
+        return Ok(Ratio::zero());
+    }
+    if numer == denom {
+        return Ok(Ratio::one());
+    }
+    let gcd = numer.gcd(&denom);
+    let (numer, denom) = (numer / gcd.clone(), denom / gcd);
+    if denom < T::zero() {
+        let negate = |x: &T| T::zero().checked_sub(x);
+        return match (negate(&numer), negate(&denom)) {
+            (Some(numer), Some(denom)) => Ok(Ratio::new_raw(numer, denom)),
+            _ => Err(RatioErrorKind::Overflow.into()),
+        };
+    }
+    Ok(Ratio::new_raw(numer, denom))
+}
+
+/// Decomposes a proper fraction into a sum of distinct unit fractions.
+///
+/// Returns the denominators in increasing order, as found by the greedy
+/// algorithm of Fibonacci and Sylvester: each step takes the largest unit
+/// fraction that fits into the remainder. So `3/4` is `[2, 4]` (`1/2 + 1/4`)
+/// and `4/13` is `[4, 18, 468]`. Zero is the empty sum.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ImproperFraction`] if `|r| >= 1`,
+/// [`RatioErrorKind::OutOfRange`] if `r` is negative, and
+/// [`RatioErrorKind::Overflow`] if an intermediate value does not fit in `T`.
+/// Greedy denominators grow quickly, so even small fractions like `5/121` need
+/// more than 64 bits.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::to_egyptian_fraction;
+///
+/// assert_eq!(to_egyptian_fraction(&Ratio::new(3, 4)).unwrap(), [2, 4]);
+/// assert_eq!(to_egyptian_fraction(&Ratio::new(6, 7)).unwrap(), [2, 3, 42]);
+/// ```
+pub fn to_egyptian_fraction<T: FlexInteger>(r: &Ratio<T>) -> Result<Vec<T>, ParseRatioError> {
+    let (mut numer, mut denom) = (r.numer().clone(), r.denom().clone());
+    if numer >= denom || numer <= -denom.clone() {
+        return Err(RatioErrorKind::ImproperFraction.into());
+    }
+    if numer.is_negative() {
+        return Err(RatioErrorKind::OutOfRange.into());
+    }
+
+    let overflow = || ParseRatioError::new(RatioErrorKind::Overflow);
+    let mut denominators = Vec::new();
+    while !numer.is_zero() {
+        // The smallest unit denominator with 1/d <= numer/denom.
+        let unit = denom.div_ceil(&numer);
+        // numer/denom - 1/unit = (numer * unit - denom) / (denom * unit)
+        let rest_numer = numer
+            .checked_mul(&unit)
+            .and_then(|n| n.checked_sub(&denom))
+            .ok_or_else(overflow)?;
+        let rest_denom = denom.checked_mul(&unit).ok_or_else(overflow)?;
+        let gcd = rest_numer.gcd(&rest_denom);
+        (numer, denom) = (rest_numer / gcd.clone(), rest_denom / gcd);
+        denominators.push(unit);
+    }
+    Ok(denominators)
+}
+
+/// Returns the prime factorization of the denominator of a ratio.
+///
+/// The factors come in increasing order, each with its multiplicity, so `1/12`
+/// gives `[(2, 2), (3, 1)]`; whole numbers give an empty list. The ratio is
+/// expected to be reduced with a positive denominator, as returned by the
+/// parsers of this crate, so the factors describe the value itself: its
+/// decimal expansion terminates exactly if all of them are 2 or 5.
+///
+/// This uses trial division, which takes up to `sqrt(denom)` steps. That is
+/// fast for any `i32` denominator and takes seconds for a large `i64` prime,
+/// but it is impractical for large `i128` or big integer denominators.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::denominator_factors;
+///
+/// assert_eq!(denominator_factors(&Ratio::new(1, 4)), [(2, 2)]);
+/// assert_eq!(denominator_factors(&Ratio::new(5, 6)), [(2, 1), (3, 1)]);
+/// assert_eq!(denominator_factors(&Ratio::new(3, 1)), []);
+/// ```
+pub fn denominator_factors<T: FlexInteger>(r: &Ratio<T>) -> Vec<(T, u32)> {
+    let mut rest = r.denom().clone();
+    let mut factors = Vec::new();
+    let mut divide_out = |rest: &mut T, factor: &T| {
+        let mut count = 0;
+        while rest.is_multiple_of(factor) {
+            *rest = rest.clone() / factor.clone();
+            count += 1;
+        }
+        if count > 0 {
+            factors.push((factor.clone(), count));
+        }
+    };
+
+    let two = T::one() + T::one();
+    divide_out(&mut rest, &two);
+    let mut candidate = two.clone() + T::one();
+    // Stop once `candidate^2 > rest`, including when the square overflows.
+    while candidate
+        .checked_mul(&candidate)
+        .is_some_and(|square| square <= rest)
+    {
+        divide_out(&mut rest, &candidate);
+        candidate = candidate + two.clone();
+    }
+    if rest > T::one() {
+        factors.push((rest, 1));
+    }
+    factors
+}
+
+/// Returns the fraction closest to `r` whose denominator is one of `nice_denoms`.
+///
+/// This turns a measured or rounded value into one that reads well, like
+/// `0.33` into `1/3` with the denominators `[2, 3, 4, 8]`. For each
+/// denominator, the numerator is `r * denom` rounded to the nearest integer,
+/// and the candidate closest to `r` wins. If several are equally close, the one
+/// with the smallest denominator wins. Since the result is reduced, its
+/// denominator may be a divisor of the chosen one: `0.49` with `[4]` is `1/2`.
+///
+/// Returns `None` if `nice_denoms` is empty, or if every candidate overflows
+/// `T`; candidates that overflow are skipped.
+///
+/// # Panics
+///
+/// Panics if a denominator in `nice_denoms` is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RationalParse, snap_to_nice};
+///
+/// let r = Ratio::<i32>::from_str_flex("0.33").unwrap();
+/// assert_eq!(snap_to_nice(&r, &[2, 3, 4, 8]), Some(Ratio::new(1, 3)));
+///
+/// let r = Ratio::<i32>::from_str_flex("-2.1").unwrap();
+/// assert_eq!(snap_to_nice(&r, &[2, 4, 8]), Some(Ratio::new(-17, 8)));
+/// ```
+pub fn snap_to_nice<T: FlexInteger>(r: &Ratio<T>, nice_denoms: &[T]) -> Option<Ratio<T>> {
+    // The distance is taken in the direction that cannot be negative, since
+    // negating the most negative value of `T` overflows.
+    let distance = |candidate: &Ratio<T>| {
+        if candidate >= r {
+            candidate.checked_sub(r)
+        } else {
+            r.checked_sub(candidate)
+        }
+    };
+
+    let mut best: Option<(Ratio<T>, Ratio<T>, &T)> = None;
+    for denom in nice_denoms {
+        assert!(denom.is_positive(), "nice denominators must be positive");
+        let Some(scaled) = r.checked_mul(&Ratio::from_integer(denom.clone())) else {
+            continue;
+        };
+        let candidate = Ratio::new(scaled.round().to_integer(), denom.clone());
+        let Some(dist) = distance(&candidate) else {
+            continue;
+        };
+        let better = match &best {
+            None => true,
+            Some((_, best_dist, best_denom)) => {
+                dist < *best_dist || (dist == *best_dist && denom < *best_denom)
+            }
+        };
+        if better {
+            best = Some((candidate, dist, denom));
+        }
+    }
+    best.map(|(candidate, _, _)| candidate)
+}
+
+/// Returns the sign of a ratio as `-1`, `0` or `1` in its integer type.
+///
+/// The parsers of this crate always return a positive denominator, so the sign
+/// is that of the numerator. This holds for ratios built with [`Ratio::new`]
+/// as well, but not necessarily for [`Ratio::new_raw`], so the denominator is
+/// taken into account too.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RationalParse, sign};
+///
+/// assert_eq!(sign(&Ratio::<i32>::from_str_flex("-3/4").unwrap()), -1);
+/// assert_eq!(sign(&Ratio::<i32>::from_str_flex("0.0").unwrap()), 0);
+/// assert_eq!(sign(&Ratio::new_raw(-1, -2)), 1);
+/// ```
+pub fn sign<T: FlexInteger>(r: &Ratio<T>) -> T {
+    r.numer().signum() * r.denom().signum()
+}
+
+/// A [`Ratio`] constructed through this crate's checked conversions.
+///
+/// `Ratio` cannot implement the standard conversion traits on behalf of this
+/// crate, so this wrapper does: it parses with [`FromStr`] using the flexible
+/// syntax of [`RationalParse::from_str_flex`], and converts from a
+/// `(numer, denom)` tuple with [`TryFrom`] like [`ratio_from_parts`]. Both
+/// report failures as a [`ParseRatioError`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{FlexRatio, RatioErrorKind};
+///
+/// let r: FlexRatio<i32> = (6, -4).try_into().unwrap();
+/// assert_eq!(r.0, Ratio::new(-3, 2));
+///
+/// let r: FlexRatio<i32> = "-1.5".parse().unwrap();
+/// assert_eq!(r.into_inner(), Ratio::new(-3, 2));
+///
+/// let err = FlexRatio::try_from((1, 0)).unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::ZeroDenominator);
+///
+/// let err = FlexRatio::try_from((i32::MIN, -1)).unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct FlexRatio<T>(pub Ratio<T>);
+
+// Derived comparisons would not carry the `Integer` bound that `Ratio` needs.
+impl<T: Clone + Integer> PartialEq for FlexRatio<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Clone + Integer> Eq for FlexRatio<T> {}
+
+impl<T> FlexRatio<T> {
+    /// Returns the wrapped ratio.
+    pub fn into_inner(self) -> Ratio<T> {
+        self.0
+    }
+}
+
+impl<T> From<FlexRatio<T>> for Ratio<T> {
+    fn from(r: FlexRatio<T>) -> Self {
+        r.0
+    }
+}
+
+impl<T: Clone + Integer + CheckedSub> TryFrom<(T, T)> for FlexRatio<T> {
+    type Error = ParseRatioError;
+
+    fn try_from((numer, denom): (T, T)) -> Result<Self, Self::Error> {
+        ratio_from_parts(numer, denom).map(FlexRatio)
+    }
+}
+
+impl<T: FlexInteger> FromStr for FlexRatio<T> {
+    type Err = ParseRatioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ratio::from_str_flex(s).map(FlexRatio)
+    }
+}
@@ -0,0 +1,82 @@
+//! Parsing of monetary amounts.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind, RationalParse};
+use num_rational::Ratio;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Matches an amount such as `"$1,234.56"`, `"-€0.5"` or `"(1,234.56)"`.
+static CURRENCY_FORMAT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?x)
+        \A\s*
+        (?P<open>\(\s*)?                       # an optional opening parenthesis,
+        (?P<sign>-)?                           # an optional minus sign,
+        \p{Sc}?                                # an optional currency symbol, then
+        (?P<int>\d{1,3}(?:,\d{3})+|\d+)        # the integer part, grouped by threes or not at all
+        (?:\.(?P<cents>\d{1,2}))?              # up to two decimals,
+        (?P<close>\s*\))?                      # an optional closing parenthesis
+        \s*\z
+        ",
+    )
+    .unwrap()
+});
+
+/// Parses a monetary amount such as `"$1,234.56"` into an exact rational.
+///
+/// The accepted format is:
+/// - an optional currency symbol (any Unicode currency symbol, e.g. `$`, `€`, `£`),
+///   optionally preceded by a minus sign,
+/// - an integer part, either plain (`"1234"`) or grouped by commas into blocks
+///   of exactly three digits (`"1,234"`),
+/// - optionally a `.` followed by one or two decimals.
+///
+/// The whole amount may be wrapped in parentheses, which is the accounting
+/// notation for a negative value: `"(1,234.56)"` is `-123456/100`. Combining
+/// parentheses with a minus sign is rejected to avoid a double negation.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] for anything that does not match the
+/// format above, including malformed grouping like `"1,23"`, and
+/// [`RatioErrorKind::Overflow`] if the amount does not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_currency;
+///
+/// assert_eq!(from_currency::<i64>("$1,234.56").unwrap(), Ratio::new(123456, 100));
+/// assert_eq!(from_currency::<i64>("(1,234.56)").unwrap(), Ratio::new(-123456, 100));
+/// assert_eq!(from_currency::<i64>("-€0.5").unwrap(), Ratio::new(-1, 2));
+/// ```
+pub fn from_currency<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let cap = CURRENCY_FORMAT
+        .captures(s)
+        .ok_or(ParseRatioError::new(RatioErrorKind::ParseError))?;
+
+    let parenthesized = match (cap.name("open"), cap.name("close")) {
+        (Some(_), Some(_)) => true,
+        (None, None) => false,
+        _ => {
+            return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+        }
+    };
+    let minus = cap.name("sign").is_some();
+    if parenthesized && minus {
+        return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+    }
+
+    // Rewrite the amount in the flexible grammar to reuse its overflow checks.
+    let mut normalized = String::with_capacity(s.len());
+    if parenthesized || minus {
+        normalized.push('-');
+    }
+    normalized.push_str(&cap["int"].replace(',', ""));
+    if let Some(cents) = cap.name("cents") {
+        normalized.push('.');
+        normalized.push_str(cents.as_str());
+    }
+    Ratio::<T>::from_str_flex(&normalized)
+}
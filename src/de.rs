@@ -0,0 +1,104 @@
+//! Deserialization of rationals from numbers and strings, enabled by the `serde`
+//! feature.
+
+use crate::{FlexInteger, FlexRatio, ParseRatioError, RatioErrorKind, RationalParse};
+use num_rational::Ratio;
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Deserializes a ratio written as an integer, a float or a string.
+///
+/// This covers every scalar form a rational field can take in formats like
+/// TOML, YAML or JSON. Integers are converted exactly, and strings are parsed
+/// with [`RationalParse::from_str_flex`], so `"1/3"` and `"1.5e-3"` work. A
+/// float is read through its shortest decimal representation, the one that
+/// round-trips, so the `0.1` of a config file is `1/10` rather than the binary
+/// value nearest to it.
+///
+/// Use it with `#[serde(deserialize_with = "num_rational_parse::deserialize_flex")]`,
+/// or use [`FlexRatio`] as the field type.
+///
+/// # Errors
+///
+/// Fails with the message of the [`ParseRatioError`] if a string does not
+/// parse, if a number does not fit in `Ratio<T>`, or if a float is infinite or
+/// NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "num_rational_parse::deserialize_flex")]
+///     ratio: Ratio<i64>,
+/// }
+///
+/// let config: Config = toml::from_str(r#"ratio = "1/3""#).unwrap();
+/// assert_eq!(config.ratio, Ratio::new(1, 3));
+///
+/// let config: Config = toml::from_str("ratio = 0.1").unwrap();
+/// assert_eq!(config.ratio, Ratio::new(1, 10));
+/// ```
+pub fn deserialize_flex<'de, D, T>(deserializer: D) -> Result<Ratio<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FlexInteger,
+{
+    deserializer.deserialize_any(FlexVisitor(PhantomData))
+}
+
+impl<'de, T: FlexInteger> Deserialize<'de> for FlexRatio<T> {
+    /// Deserializes like [`deserialize_flex`].
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_flex(deserializer).map(FlexRatio)
+    }
+}
+
+struct FlexVisitor<T>(PhantomData<T>);
+
+impl<T: FlexInteger> FlexVisitor<T> {
+    fn integer<E: Error>(value: Option<T>) -> Result<Ratio<T>, E> {
+        value
+            .map(Ratio::from_integer)
+            .ok_or_else(|| E::custom(ParseRatioError::new(RatioErrorKind::Overflow)))
+    }
+}
+
+impl<T: FlexInteger> Visitor<'_> for FlexVisitor<T> {
+    type Value = Ratio<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a rational number as an integer, a float or a string")
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        Self::integer(T::from_i64(v))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Self::integer(T::from_u64(v))
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+        Self::integer(T::from_i128(v))
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        Self::integer(T::from_u128(v))
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        // `{:e}` writes the shortest representation that round-trips, without
+        // spelling out the zeros of large or small magnitudes. Infinities and
+        // NaN come out as words, which fail to parse.
+        self.visit_str(&format!("{v:e}"))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ratio::from_str_flex(v).map_err(E::custom)
+    }
+}
@@ -0,0 +1,35 @@
+//! Reading rationals from environment variables, enabled by the `env` feature.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind, RationalParse};
+use num_rational::Ratio;
+use std::env::{self, VarError};
+
+/// Parses the environment variable `var`, falling back to `default` if it is unset.
+///
+/// The value is parsed with [`RationalParse::from_str_flex`]. A variable that is
+/// set but invalid is an error rather than silently replaced by the default.
+///
+/// # Errors
+///
+/// Returns the parse error if the variable is set to an invalid value, and
+/// [`RatioErrorKind::ParseError`] if it is not valid Unicode.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_env_or;
+///
+/// let r = from_env_or("MY_APP_UNSET_RATIO", Ratio::new(1, 2)).unwrap();
+/// assert_eq!(r, Ratio::<i32>::new(1, 2));
+/// ```
+pub fn from_env_or<T: FlexInteger>(
+    var: &str,
+    default: Ratio<T>,
+) -> Result<Ratio<T>, ParseRatioError> {
+    match env::var(var) {
+        Ok(value) => Ratio::<T>::from_str_flex(&value),
+        Err(VarError::NotPresent) => Ok(default),
+        Err(VarError::NotUnicode(_)) => Err(ParseRatioError::new(RatioErrorKind::ParseError)),
+    }
+}
@@ -0,0 +1,123 @@
+//! Bounded arithmetic over flexible rational terms.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind, RationalParse};
+use num_rational::Ratio;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+
+/// Parses a sum of rational terms, such as `"1/2 + 1/3 - 1/6"`.
+///
+/// Terms are separated by `+` or `-` and evaluated left to right; there is no
+/// support for parentheses or other operators. Each term is parsed with
+/// [`RationalParse::from_str_flex`] and may carry its own sign, so `"1 - -1/2"`
+/// is `3/2`. A sign directly following an exponent marker belongs to the
+/// exponent, so `"1e-3 + 1"` is two terms.
+///
+/// # Errors
+///
+/// Returns the error of the first term that fails to parse,
+/// [`RatioErrorKind::ParseError`] for an empty term (e.g. `"1 +"`), and
+/// [`RatioErrorKind::Overflow`] if the running total does not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_sum;
+///
+/// assert_eq!(from_sum::<i32>("1/2 + 1/3 - 1/6").unwrap(), Ratio::new(2, 3));
+/// assert_eq!(from_sum::<i32>("0.5 + 1e-1").unwrap(), Ratio::new(3, 5));
+/// ```
+pub fn from_sum<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let mut total = Ratio::<T>::from_integer(T::zero());
+    for (negate, term) in SumTerms::new(s) {
+        let value = Ratio::<T>::from_str_flex(term)?;
+        let next = if negate {
+            total.checked_sub(&value)
+        } else {
+            total.checked_add(&value)
+        };
+        total = next.ok_or(ParseRatioError::new(RatioErrorKind::Overflow))?;
+    }
+    Ok(total)
+}
+
+/// Parses a product of rational factors, such as `"3/4 * 2/5"` or `"1.5 × 2"`.
+///
+/// Factors are separated by `*` or `×` and multiplied left to right; as with
+/// [`from_sum`], there is no support for parentheses or other operators. Each
+/// factor is parsed with [`RationalParse::from_str_flex`], so `"1/2 * -4"` is
+/// `-2`. A zero factor makes the product zero, but every factor must still be
+/// valid.
+///
+/// # Errors
+///
+/// Returns the error of the first factor that fails to parse, including
+/// [`RatioErrorKind::ParseError`] for an empty factor (e.g. `"2 *"`) and
+/// [`RatioErrorKind::ZeroDenominator`] for a factor like `"1/0"`, and
+/// [`RatioErrorKind::Overflow`] if the running product does not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_product;
+///
+/// assert_eq!(from_product::<i32>("3/4 * 2/5").unwrap(), Ratio::new(3, 10));
+/// assert_eq!(from_product::<i32>("1.5 × 2e1").unwrap(), Ratio::new(30, 1));
+/// ```
+pub fn from_product<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let mut total = Ratio::<T>::from_integer(T::one());
+    for factor in s.split(['*', '×']) {
+        let value = Ratio::<T>::from_str_flex(factor)?;
+        total = total
+            .checked_mul(&value)
+            .ok_or(ParseRatioError::new(RatioErrorKind::Overflow))?;
+    }
+    Ok(total)
+}
+
+/// Splits a sum into its terms, each paired with whether it is subtracted.
+struct SumTerms<'a> {
+    rest: &'a str,
+    negate: bool,
+    done: bool,
+}
+
+impl<'a> SumTerms<'a> {
+    fn new(s: &'a str) -> Self {
+        SumTerms {
+            rest: s,
+            negate: false,
+            done: false,
+        }
+    }
+}
+
+impl<'a> Iterator for SumTerms<'a> {
+    type Item = (bool, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        // A term may start with whitespace and its own sign; any later `+` or `-`
+        // is an operator unless it is the sign of an exponent.
+        let mut in_prefix = true;
+        let mut prev = None;
+        for (i, c) in self.rest.char_indices() {
+            if in_prefix {
+                in_prefix = c.is_whitespace() || c == '+' || c == '-';
+            } else if (c == '+' || c == '-') && !matches!(prev, Some('e' | 'E')) {
+                let term = (self.negate, &self.rest[..i]);
+                self.negate = c == '-';
+                self.rest = &self.rest[i + 1..];
+                return Some(term);
+            }
+            prev = Some(c);
+        }
+
+        self.done = true;
+        Some((self.negate, self.rest))
+    }
+}
@@ -0,0 +1,359 @@
+//! Formatting of rationals as decimal strings.
+
+use crate::{FlexInteger, RationalParse, has_terminating_decimal};
+use num_rational::Ratio;
+use std::fmt::Display;
+
+/// The decimal digits of the absolute value of a ratio, produced by long division.
+struct Digits<T> {
+    /// Digits of the integer part, most significant first; empty if it is zero.
+    integer: Vec<u8>,
+    /// The remainder of the division, always in `0..denom`.
+    rem: T,
+    denom: T,
+}
+
+impl<T: FlexInteger + Display> Digits<T> {
+    fn new(r: &Ratio<T>) -> Self {
+        let (quot, rem) = r.numer().div_rem(r.denom());
+        // Going through `Display` avoids negating the quotient, which could
+        // overflow for the most negative value of `T`.
+        let integer = if quot.is_zero() {
+            Vec::new()
+        } else {
+            quot.to_string()
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|b| b - b'0')
+                .collect()
+        };
+        Digits {
+            integer,
+            // `|rem| < denom`, so this cannot overflow.
+            rem: rem.abs(),
+            denom: r.denom().clone(),
+        }
+    }
+
+    /// Returns the next fractional digit.
+    ///
+    /// This computes `(10 * rem) / denom` by repeated addition, so that no
+    /// intermediate value exceeds `denom`.
+    fn next_fraction_digit(&mut self) -> u8 {
+        let mut digit = 0;
+        let mut acc = T::zero();
+        let gap = self.denom.clone() - self.rem.clone();
+        for _ in 0..10 {
+            if acc >= gap {
+                acc = acc - gap.clone();
+                digit += 1;
+            } else {
+                acc = acc + self.rem.clone();
+            }
+        }
+        self.rem = acc;
+        digit
+    }
+
+    /// Returns `true` if no non-zero fractional digits remain.
+    fn is_exact(&self) -> bool {
+        self.rem.is_zero()
+    }
+}
+
+/// Returns at most `limit` significant digits of a non-zero ratio, rounded half
+/// to even, along with the decimal exponent of the first one and whether digits
+/// were cut off.
+///
+/// The digits may end in zeros, and fall short of `limit` if the value needs
+/// fewer digits or rounding carried into them.
+fn significant_digits<T>(r: &Ratio<T>, limit: usize) -> (Vec<u8>, i64, bool)
+where
+    T: FlexInteger + Display,
+{
+    let mut digits = Digits::new(r);
+    let mut mantissa;
+    let mut exponent: i64;
+    if !digits.integer.is_empty() {
+        exponent = digits.integer.len() as i64 - 1;
+        mantissa = std::mem::take(&mut digits.integer);
+    } else {
+        // Skip the leading zeros of a value below one.
+        exponent = -1;
+        let mut digit = digits.next_fraction_digit();
+        while digit == 0 {
+            exponent -= 1;
+            digit = digits.next_fraction_digit();
+        }
+        mantissa = vec![digit];
+    }
+    // Produce one digit more than needed to decide the rounding direction.
+    while !digits.is_exact() && mantissa.len() <= limit {
+        mantissa.push(digits.next_fraction_digit());
+    }
+
+    let rounded = mantissa.len() > limit;
+    if rounded {
+        let dropped = mantissa.split_off(limit);
+        let past_half = dropped[1..].iter().any(|&d| d != 0) || !digits.is_exact();
+        let round_up = match dropped[0] {
+            6..=9 => true,
+            5 => past_half || mantissa[limit - 1] % 2 == 1,
+            _ => false,
+        };
+        if round_up {
+            match mantissa.iter().rposition(|&d| d != 9) {
+                Some(i) => {
+                    mantissa[i] += 1;
+                    mantissa.truncate(i + 1);
+                }
+                // All nines carry into a new leading digit.
+                None => {
+                    mantissa = vec![1];
+                    exponent += 1;
+                }
+            }
+        }
+    }
+    (mantissa, exponent, rounded)
+}
+
+/// Formats a ratio in scientific notation with exactly `sig_digits` significant
+/// digits, e.g. `1/8` with 3 digits as `"1.25e-1"`.
+///
+/// The mantissa has a single non-zero digit before the decimal point and is
+/// padded with trailing zeros if the value needs fewer digits. Zero is rendered
+/// as `"0.00e0"` (for 3 digits). The output can be parsed back with
+/// [`RationalParse::from_str_flex`](crate::RationalParse::from_str_flex).
+///
+/// This function never rounds: if the value cannot be written exactly with
+/// `sig_digits` significant digits (like `1/3`, or `123` with 2 digits), or if
+/// `sig_digits` is zero, `None` is returned. Only the digits of the value are
+/// computed, so a huge `sig_digits` is cheap for a value like `1/3`; `None` is
+/// also returned if the padded output cannot be allocated.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::to_scientific_string;
+///
+/// assert_eq!(to_scientific_string(&Ratio::new(1, 8), 3).unwrap(), "1.25e-1");
+/// assert_eq!(to_scientific_string(&Ratio::new(-1500, 1), 2).unwrap(), "-1.5e3");
+/// assert_eq!(to_scientific_string(&Ratio::new(1, 3), 10), None);
+/// ```
+pub fn to_scientific_string<T>(r: &Ratio<T>, sig_digits: usize) -> Option<String>
+where
+    T: FlexInteger + Display,
+{
+    if sig_digits == 0 {
+        return None;
+    }
+
+    // A repeating expansion never fits, however many digits are asked for.
+    if !has_terminating_decimal(r) {
+        return None;
+    }
+
+    let mut digits = Digits::new(r);
+    let mut mantissa = Vec::new();
+    let exponent: i64;
+
+    if !digits.integer.is_empty() {
+        exponent = digits.integer.len() as i64 - 1;
+        let (kept, dropped) = digits
+            .integer
+            .split_at(sig_digits.min(digits.integer.len()));
+        if dropped.iter().any(|&d| d != 0) {
+            return None;
+        }
+        mantissa.extend_from_slice(kept);
+    } else if digits.is_exact() {
+        exponent = 0;
+    } else {
+        // Skip the leading zeros of a value below one.
+        let mut exp = -1;
+        let mut digit = digits.next_fraction_digit();
+        while digit == 0 {
+            exp -= 1;
+            digit = digits.next_fraction_digit();
+        }
+        exponent = exp;
+        mantissa.push(digit);
+    }
+
+    // The expansion terminates, so this stops at its end even for a huge
+    // `sig_digits`; the rest of the requested digits are padding.
+    while !digits.is_exact() && mantissa.len() < sig_digits {
+        mantissa.push(digits.next_fraction_digit());
+    }
+    if !digits.is_exact() {
+        return None;
+    }
+
+    let mut out = String::new();
+    out.try_reserve(sig_digits.saturating_add(24)).ok()?;
+    if r.numer().is_negative() {
+        out.push('-');
+    }
+    let padding = std::iter::repeat(0).take(sig_digits - mantissa.len());
+    for (i, d) in mantissa.iter().copied().chain(padding).enumerate() {
+        if i == 1 {
+            out.push('.');
+        }
+        out.push(char::from(b'0' + d));
+    }
+    out.push('e');
+    out.push_str(&exponent.to_string());
+    Some(out)
+}
+
+/// Formats a ratio in normalized scientific notation, e.g. `12300` as `"1.23e4"`.
+///
+/// The mantissa has a single non-zero digit before the decimal point and no
+/// trailing zeros, so every value has exactly one normalized form; zero is
+/// `"0e0"`. Unlike [`to_scientific_string`], the number of digits follows from
+/// the value.
+///
+/// Without a `precision`, the value is written exactly, and `None` is returned
+/// if its decimal expansion does not terminate (like `1/3`). With
+/// `Some(precision)`, at most that many significant digits are written,
+/// rounding half to even, so `1/3` with a precision of 4 is `"3.333e-1"`;
+/// `Some(0)` returns `None`.
+///
+/// The output is parsed back with [`RationalParse::from_str_flex`] before it is
+/// returned, and `None` is returned if it does not fit in `Ratio<T>`, as with
+/// `1/125` for `Ratio<i8>`, whose output `"8e-3"` needs a denominator of 1000.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::to_normalized_scientific;
+///
+/// assert_eq!(to_normalized_scientific(&Ratio::new(12300, 1), None).unwrap(), "1.23e4");
+/// assert_eq!(to_normalized_scientific(&Ratio::new(-1, 8), None).unwrap(), "-1.25e-1");
+/// assert_eq!(to_normalized_scientific(&Ratio::new(2, 3), None), None);
+/// assert_eq!(to_normalized_scientific(&Ratio::new(2, 3), Some(3)).unwrap(), "6.67e-1");
+/// ```
+pub fn to_normalized_scientific<T>(r: &Ratio<T>, precision: Option<usize>) -> Option<String>
+where
+    T: FlexInteger + Display,
+{
+    if precision == Some(0) {
+        return None;
+    }
+    if r.numer().is_zero() {
+        return Some("0e0".to_owned());
+    }
+    let limit = match precision {
+        Some(limit) => limit,
+        // Without a limit, long division has to end by itself.
+        None if has_terminating_decimal(r) => usize::MAX,
+        None => return None,
+    };
+
+    let (mut mantissa, exponent, rounded) = significant_digits(r, limit);
+    while mantissa.len() > 1 && mantissa.last() == Some(&0) {
+        mantissa.pop();
+    }
+
+    let mut out = String::with_capacity(mantissa.len() + 8);
+    if r.numer().is_negative() {
+        out.push('-');
+    }
+    for (i, d) in mantissa.iter().enumerate() {
+        if i == 1 {
+            out.push('.');
+        }
+        out.push(char::from(b'0' + d));
+    }
+    out.push('e');
+    out.push_str(&exponent.to_string());
+
+    let parsed = Ratio::<T>::from_str_flex(&out).ok()?;
+    debug_assert!(rounded || parsed == *r);
+    Some(out)
+}
+
+/// The most significant figures written by [`to_sig_figs_string`].
+///
+/// Larger requests are clamped to this, which bounds the time and memory of a
+/// single call: a value like `1/3` has a digit for every figure requested.
+pub const MAX_SIG_FIGS: u32 = 100_000;
+
+/// Formats a ratio with `figs` significant figures, rounding half to even.
+///
+/// Trailing zeros are kept, since they are significant: `1/2` with 3 figures
+/// is `"0.500"`. As with C's `%g`, the form depends on the decimal exponent `e`
+/// of the rounded value, where `1234` has `e = 3` and `0.05` has `e = -2`:
+///
+/// - for `-4 <= e < figs`, the value is written as a plain decimal, like
+///   `"0.333"` or `"1200"`;
+/// - otherwise it is written in scientific notation, like `"1.2e3"` or
+///   `"1.00e-5"`, which can be parsed back with
+///   [`RationalParse::from_str_flex`](crate::RationalParse::from_str_flex).
+///
+/// Zero is written as a decimal, e.g. `"0.00"` with 3 figures. At most
+/// [`MAX_SIG_FIGS`] figures are written.
+///
+/// # Panics
+///
+/// Panics if `figs` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::to_sig_figs_string;
+///
+/// assert_eq!(to_sig_figs_string(&Ratio::new(1, 3), 3), "0.333");
+/// assert_eq!(to_sig_figs_string(&Ratio::new(1234, 1), 2), "1.2e3");
+/// assert_eq!(to_sig_figs_string(&Ratio::new(-1, 2), 3), "-0.500");
+/// assert_eq!(to_sig_figs_string(&Ratio::new(1, 100000), 2), "1.0e-5");
+/// ```
+pub fn to_sig_figs_string<T>(r: &Ratio<T>, figs: u32) -> String
+where
+    T: FlexInteger + Display,
+{
+    assert!(figs > 0, "significant figures must be positive");
+    let figs = figs.min(MAX_SIG_FIGS) as usize;
+    let (mut mantissa, exponent) = if r.numer().is_zero() {
+        (Vec::new(), 0)
+    } else {
+        let (mantissa, exponent, _) = significant_digits(r, figs);
+        (mantissa, exponent)
+    };
+    mantissa.resize(figs, 0);
+
+    let mut out = String::with_capacity(figs.saturating_add(8));
+    if r.numer().is_negative() {
+        out.push('-');
+    }
+    let push_digits = |out: &mut String, digits: &[u8]| {
+        out.extend(digits.iter().map(|&d| char::from(b'0' + d)));
+    };
+    if (-4..figs as i64).contains(&exponent) {
+        if exponent < 0 {
+            out.push_str("0.");
+            out.extend(std::iter::repeat('0').take((-exponent - 1) as usize));
+            push_digits(&mut out, &mantissa);
+        } else {
+            let (integer, fraction) = mantissa.split_at(exponent as usize + 1);
+            push_digits(&mut out, integer);
+            if !fraction.is_empty() {
+                out.push('.');
+                push_digits(&mut out, fraction);
+            }
+        }
+    } else {
+        push_digits(&mut out, &mantissa[..1]);
+        if figs > 1 {
+            out.push('.');
+            push_digits(&mut out, &mantissa[1..]);
+        }
+        out.push('e');
+        out.push_str(&exponent.to_string());
+    }
+    out
+}
@@ -20,23 +20,238 @@
 //! assert_eq!(r3, Ratio::new(-1, 2));
 //! ```
 
+mod batch;
+#[cfg(feature = "cache")]
+mod cache;
+mod convert;
+mod currency;
+#[cfg(feature = "serde")]
+mod de;
+#[cfg(feature = "env")]
+mod env;
+mod expr;
+mod format;
+#[cfg(feature = "packed")]
+mod packed;
+mod partial;
+mod range;
+mod round;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "util")]
+mod util;
+
+#[cfg(feature = "std")]
+pub use batch::{MAX_PREFIXED_LEN, from_length_prefixed};
+pub use batch::{
+    RatioStats, parse_csv_column, parse_flex_max, parse_flex_min, parse_whitespace_separated,
+    ratio_stats, validate_all,
+};
+#[cfg(feature = "cache")]
+pub use cache::CachedParser;
+pub use convert::{
+    FlexRatio, denominator_factors, ratio_from_parts, sign, snap_to_nice, to_egyptian_fraction,
+    widen,
+};
+pub use currency::from_currency;
+#[cfg(feature = "serde")]
+pub use de::deserialize_flex;
+#[cfg(feature = "env")]
+pub use env::from_env_or;
+pub use expr::{from_product, from_sum};
+pub use format::{
+    MAX_SIG_FIGS, to_normalized_scientific, to_scientific_string, to_sig_figs_string,
+};
+#[cfg(feature = "packed")]
+pub use packed::{from_bytes_packed, to_bytes_packed};
+pub use partial::{FeedResult, PartialParser, from_chars_flex};
+pub use range::{parse_range, parse_range_with};
+pub use round::{round_to_decimals, round_to_decimals_half_up};
+#[cfg(feature = "util")]
+pub use util::from_kv;
+
 use num_integer::Integer;
 use num_rational::Ratio;
-use num_traits::{CheckedAdd, CheckedMul, FromPrimitive, Signed};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, One, Signed, ToPrimitive, Zero,
+};
 use regex::Regex;
+use std::borrow::Cow;
 use std::str::FromStr;
 
 /// An error which can be returned when parsing a ratio.
-#[derive(Copy, Clone, Debug, PartialEq)]
+///
+/// Errors compare equal if they have the same [`kind`](Self::kind). The
+/// [`overflow_source`](Self::overflow_source) and the input recorded with the
+/// `error-input` feature are details of where the error came from, and are not
+/// compared; use their accessors to tell them apart.
+#[derive(Copy, Clone, Debug)]
 pub struct ParseRatioError {
     kind: RatioErrorKind,
+    overflow_source: Option<OverflowSource>,
+    #[cfg(feature = "error-input")]
+    input: Option<InputSnippet>,
+}
+
+impl PartialEq for ParseRatioError {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
 }
 
 impl ParseRatioError {
+    pub(crate) const fn new(kind: RatioErrorKind) -> Self {
+        ParseRatioError {
+            kind,
+            overflow_source: None,
+            #[cfg(feature = "error-input")]
+            input: None,
+        }
+    }
+
+    /// Returns the error for an overflow of one side of a fraction.
+    pub(crate) const fn overflow_in(source: OverflowSource) -> Self {
+        ParseRatioError {
+            kind: RatioErrorKind::Overflow,
+            overflow_source: Some(source),
+            #[cfg(feature = "error-input")]
+            input: None,
+        }
+    }
+
+    /// Returns the input that failed to parse, cut to its first
+    /// [`MAX_INPUT_LEN`](Self::MAX_INPUT_LEN) bytes, or fewer if configured
+    /// with [`RationalParser::error_input_len`].
+    ///
+    /// The input is recorded by [`RationalParser::parse`], and therefore by
+    /// [`RationalParse::from_str_flex`] and the helpers built on it, as well as
+    /// by [`from_fixed_point`], [`from_percent_of`], [`from_percent_change`]
+    /// and [`from_space_pair`], which rewrite their input first. Errors
+    /// created elsewhere, like the [`RatioErrorKind::NonInteger`] of
+    /// [`from_str_flex_integer`], return `None`. A longer input is cut back to
+    /// the last character boundary within the limit, so the error stays `Copy`
+    /// and never allocates.
+    ///
+    /// Requires the `error-input` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use num_rational_parse::RationalParse;
+    ///
+    /// let err = Rational32::from_str_flex("3/x").unwrap_err();
+    /// assert_eq!(err.input(), Some("3/x"));
+    /// ```
+    #[cfg(feature = "error-input")]
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_ref().map(InputSnippet::as_str)
+    }
+
+    /// The maximum number of bytes of input kept by an error, and the default
+    /// of [`RationalParser::error_input_len`].
+    ///
+    /// Requires the `error-input` feature.
+    #[cfg(feature = "error-input")]
+    pub const MAX_INPUT_LEN: usize = 64;
+
     /// Returns the specific type of error that occurred.
     pub fn kind(&self) -> &RatioErrorKind {
         &self.kind
     }
+
+    /// Returns which part of the ratio overflowed, if known.
+    ///
+    /// This is only set for [`RatioErrorKind::Overflow`] errors where the
+    /// numerator or the denominator alone did not fit in the target type,
+    /// e.g. `"1/2147483648"` for a `Ratio<i32>`. Overflows of intermediate
+    /// results (such as in [`from_sum`]) return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_rational::Rational32;
+    /// use num_rational_parse::{OverflowSource, RationalParse};
+    ///
+    /// let err = Rational32::from_str_flex("1/2147483648").unwrap_err();
+    /// assert_eq!(err.overflow_source(), Some(OverflowSource::Denominator));
+    /// ```
+    pub fn overflow_source(&self) -> Option<OverflowSource> {
+        self.overflow_source
+    }
+}
+
+/// The start of an input, stored inline so that [`ParseRatioError`] stays `Copy`.
+#[cfg(feature = "error-input")]
+#[derive(Copy, Clone, Debug)]
+struct InputSnippet {
+    bytes: [u8; ParseRatioError::MAX_INPUT_LEN],
+    len: u8,
+}
+
+#[cfg(feature = "error-input")]
+impl InputSnippet {
+    /// Keeps at most `max_len` bytes of `input`, up to the inline capacity.
+    fn new(input: &str, max_len: usize) -> Self {
+        let mut len = input.len().min(max_len).min(ParseRatioError::MAX_INPUT_LEN);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut bytes = [0; ParseRatioError::MAX_INPUT_LEN];
+        bytes[..len].copy_from_slice(&input.as_bytes()[..len]);
+        InputSnippet {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..usize::from(self.len)])
+            .expect("input is cut at a character boundary")
+    }
+}
+
+/// The part of a ratio that overflowed, as returned by
+/// [`ParseRatioError::overflow_source`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowSource {
+    /// The numerator, including the digits of a decimal and a positive exponent.
+    Numerator,
+    /// The denominator, including the power of ten of a negative exponent.
+    Denominator,
+}
+
+impl From<RatioErrorKind> for ParseRatioError {
+    /// Creates an error of the given kind, without an overflow source.
+    fn from(kind: RatioErrorKind) -> Self {
+        ParseRatioError::new(kind)
+    }
+}
+
+impl From<std::num::ParseIntError> for ParseRatioError {
+    /// Folds an integer parsing error into this crate's error type.
+    ///
+    /// Overflows in either direction become [`RatioErrorKind::Overflow`], and
+    /// every other failure becomes [`RatioErrorKind::ParseError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_rational_parse::{ParseRatioError, RatioErrorKind};
+    ///
+    /// let err = ParseRatioError::from("300".parse::<u8>().unwrap_err());
+    /// assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+    /// ```
+    fn from(e: std::num::ParseIntError) -> Self {
+        use std::num::IntErrorKind;
+
+        match e.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                ParseRatioError::new(RatioErrorKind::Overflow)
+            }
+            _ => ParseRatioError::new(RatioErrorKind::ParseError),
+        }
+    }
 }
 
 impl std::fmt::Display for ParseRatioError {
@@ -62,7 +277,77 @@ pub enum RatioErrorKind {
     ///
     /// This occurs if the numerator, denominator, or intermediate values
     /// overflow the capacity of the integer type `T`.
+    /// [`ParseRatioError::overflow_source`] tells which part overflowed.
     Overflow,
+    /// The input had no leading sign, but one was required.
+    ///
+    /// Only returned by parsers configured with
+    /// [`RationalParser::require_sign`].
+    MissingSign,
+    /// The absolute value was not less than one, but a proper fraction was
+    /// required.
+    ///
+    /// Only returned by parsers configured with
+    /// [`RationalParser::require_proper_fraction`].
+    ImproperFraction,
+    /// The value was outside of the allowed range.
+    ///
+    /// Returned by range-checking helpers such as [`from_str_flex_clamped01`].
+    OutOfRange,
+    /// An exponent marker was not followed by any digits.
+    ///
+    /// This occurs for inputs such as `"3e"`, `"3E+"` or `"1.5e-"`.
+    DanglingExponent,
+    /// The input contained a decimal digit outside of ASCII.
+    ///
+    /// Only the ASCII digits `0`-`9` are accepted. Other Unicode decimal digits
+    /// (e.g. Arabic-Indic `٣` or fullwidth `３`) are reported separately from
+    /// other invalid characters, as they may be used to spoof numbers.
+    NonAsciiDigit {
+        /// The byte offset of the first such digit in the input.
+        position: usize,
+    },
+    /// The start of a range was greater than its end.
+    ///
+    /// Returned by [`parse_range`] and [`parse_range_with`].
+    InvalidRange,
+    /// The value was not a whole number, but an integer was required.
+    ///
+    /// Returned by [`from_str_flex_integer`].
+    NonInteger,
+    /// The decimal expansion of the value repeats, but a terminating one was
+    /// required.
+    ///
+    /// Only returned by parsers configured with
+    /// [`RationalParser::require_terminating_decimal`].
+    NonTerminating,
+    /// The requested key did not occur in the input.
+    ///
+    /// Returned by `from_kv`, which requires the `util` feature.
+    KeyNotFound,
+    /// A decimal had more significant digits than allowed.
+    ///
+    /// Only returned by parsers configured with
+    /// [`RationalParser::max_significant_digits`].
+    TooManyDigits,
+    /// A digit group started with a zero that carries no value, as in `"007"`.
+    ///
+    /// Only returned by parsers configured with
+    /// [`RationalParser::forbid_leading_zeros`].
+    LeadingZero,
+    /// A fraction had a decimal point in its numerator or denominator, as in
+    /// `"3.2/7"`.
+    ///
+    /// Decimals and fractions cannot be combined; write `"32/70"` instead.
+    MixedFractionDecimal,
+    /// The reduced denominator was not one of the allowed values.
+    ///
+    /// Returned by [`from_str_flex_with_allowed_denoms`].
+    DenominatorNotAllowed,
+    /// The value was negative, but the target type is unsigned.
+    ///
+    /// Returned by [`from_str_flex_unsigned`].
+    UnsignedNegative,
 }
 
 impl RatioErrorKind {
@@ -71,6 +356,20 @@ impl RatioErrorKind {
             RatioErrorKind::ParseError => "failed to parse integer",
             RatioErrorKind::ZeroDenominator => "zero value denominator",
             RatioErrorKind::Overflow => "overflow",
+            RatioErrorKind::MissingSign => "missing sign",
+            RatioErrorKind::ImproperFraction => "not a proper fraction",
+            RatioErrorKind::OutOfRange => "value out of range",
+            RatioErrorKind::DanglingExponent => "missing exponent digits",
+            RatioErrorKind::NonAsciiDigit { .. } => "non-ASCII digit",
+            RatioErrorKind::InvalidRange => "range start is greater than its end",
+            RatioErrorKind::NonInteger => "not an integer",
+            RatioErrorKind::NonTerminating => "decimal expansion does not terminate",
+            RatioErrorKind::KeyNotFound => "key not found",
+            RatioErrorKind::TooManyDigits => "too many significant digits",
+            RatioErrorKind::LeadingZero => "leading zero in digit group",
+            RatioErrorKind::MixedFractionDecimal => "decimal point in a fraction",
+            RatioErrorKind::DenominatorNotAllowed => "denominator not allowed",
+            RatioErrorKind::UnsignedNegative => "negative value for unsigned type",
         }
     }
 }
@@ -96,12 +395,79 @@ pub trait RationalParse: Sized {
     /// - `"3.1415"` (Decimal)
     /// - `"-47e-2"` (Scientific notation)
     ///
+    /// A decimal point needs at least one digit next to it: `".5"`, `"5."` and
+    /// `".0e5"` are accepted, while `"."`, `"-."` and `".e5"` are not. A trailing
+    /// point may be followed directly by an exponent: the empty fraction adds no
+    /// decimal digits, so `"3.e5"` is the same as `"3e5"`.
+    ///
+    /// Trailing zeros of a decimal are ignored, and the exponent is combined with
+    /// the scale of the remaining decimal digits before anything is multiplied.
+    /// Likewise, trailing zeros of an integer cancel against a negative exponent.
+    /// Only the significant digits therefore have to fit in the target type:
+    /// `"1.500e-3"` is `3/2000`, and `"2.147483647e9"` and `"100000000000e-11"`
+    /// fit in a `Ratio<i32>`.
+    ///
+    /// Leading zeros of an exponent are ignored however many there are, so
+    /// `"1e05"` is `100000` and `"1e000000000010"` is `10^10`.
+    ///
+    /// Leading and trailing whitespace is ignored, including Unicode whitespace
+    /// such as the no-break space (U+00A0), the thin space (U+2009) and the
+    /// ideographic space (U+3000) found in text copied from rich-text sources.
+    /// Whitespace inside the number is only allowed around the `/`, so
+    /// `"1\u{2009}000"` is rejected.
+    ///
+    /// The result is reduced, and its denominator is always positive, so the
+    /// sign of the value is the sign of the numerator; see [`sign`].
+    ///
     /// # Errors
     ///
     /// Returns [`ParseRatioError`] if the string is not a valid rational number string
     /// or if it represents a valid number that cannot be represented by the target type
     /// (e.g. overflow).
     fn from_str_flex(s: &str) -> Result<Self, ParseRatioError>;
+
+    /// Parses a string like [`from_str_flex`](Self::from_str_flex), converting
+    /// an error with `f`.
+    ///
+    /// This is the same as `from_str_flex(s).map_err(f)`, for integrating with
+    /// an application's error type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use num_rational_parse::RationalParse;
+    ///
+    /// let err = Ratio::<i32>::from_str_flex_map_err("1/0", |e| e.to_string());
+    /// assert_eq!(err.unwrap_err(), "zero value denominator");
+    /// ```
+    fn from_str_flex_map_err<E>(s: &str, f: impl FnOnce(ParseRatioError) -> E) -> Result<Self, E> {
+        Self::from_str_flex(s).map_err(f)
+    }
+
+    /// Parses a string like [`from_str_flex`](Self::from_str_flex), converting
+    /// an error with `f`, which also receives the input.
+    ///
+    /// The input is passed as given, before any whitespace is trimmed, so the
+    /// resulting error can quote what the caller actually parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use num_rational_parse::RationalParse;
+    ///
+    /// let err = Ratio::<i8>::from_str_flex_map_err_with_input("300", |e, input| {
+    ///     format!("{input:?}: {e}")
+    /// });
+    /// assert_eq!(err.unwrap_err(), "\"300\": overflow");
+    /// ```
+    fn from_str_flex_map_err_with_input<E>(
+        s: &str,
+        f: impl FnOnce(ParseRatioError, &str) -> E,
+    ) -> Result<Self, E> {
+        Self::from_str_flex(s).map_err(|e| f(e, s))
+    }
 }
 
 use std::sync::LazyLock;
@@ -124,150 +490,2431 @@ static RATIONAL_FORMAT: LazyLock<Regex> = LazyLock::new(|| {
         (?P<sign>[-+]?)                        # an optional sign, then
         (?P<num>\d*|\d+(_\d+)*)                # numerator (possibly empty)
         (?:                                    # followed by
-           (?:\s*/\s*(?P<denom>\d+(_\d+)*))?   # an optional denominator
+           (?:\s*/\s*(?P<denom_sign>[-+]?)      # an optional denominator, whose sign
+              (?P<denom>(\d+(_\d+)*)?))?        # and absence are checked later
         |                                      # or
            (?:\.(?P<decimal>\d*|\d+(_\d+)*))?  # an optional fractional part
-           (?:E(?P<exp>[-+]?\d+(_\d+)*))?      # and optional exponent
         )
+        (?:(?P<marker>[EP])                    # and optional exponent, decimal or binary
+           (?P<exp>[-+]?(\d+(_\d+)*)?))?       # (digits and use on fractions checked later)
         \s*\z                                  # and optional whitespace to finish
         ",
     )
     .unwrap()
 });
 
-impl<T> RationalParse for Ratio<T>
-where
-    T: Clone + Integer + Signed + FromStr + CheckedMul + CheckedAdd + FromPrimitive,
-    <T as FromStr>::Err: std::fmt::Display,
+/// Matches a fraction with a decimal point on either side of the `/`, as in
+/// `"3.2/7"`, which the grammar does not allow.
+static MIXED_FRACTION_DECIMAL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?x)
+        \A\s*[-+]?
+        (?:\d+(_\d+)*(\.(\d+(_\d+)*)?)?|\.\d+(_\d+)*)
+        \s*/\s*[-+]?
+        (?:\d+(_\d+)*(\.(\d+(_\d+)*)?)?|\.\d+(_\d+)*)
+        \s*\z
+        ",
+    )
+    .unwrap()
+});
+
+/// Matches a trailing `percent` or `per cent`, with the whitespace before it.
+static PERCENT_WORD: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\s*per\s*cent\s*\z").unwrap());
+
+/// Matches the word `per` used as a fraction separator, with its whitespace.
+static PER_WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\s*per\s*").unwrap());
+
+/// Matches a mixed number joined by the word `and`, like `"1 and 3/4"`.
+static MIXED_NUMBER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?xi)
+        \A\s*(?P<whole>[-+]?\d+(_\d+)*)         # a whole number,
+        \s+and\s+                               # the word `and`,
+        (?P<fraction>\d+(_\d+)*\s*/\s*\d+(_\d+)*) # and an unsigned fraction
+        \s*\z
+        ",
+    )
+    .unwrap()
+});
+
+/// Matches whitespace after the sign of a numerator or denominator, as in `"- 3 / - 2"`.
+static SIGN_SPACE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?P<sign>(?:\A|/)\s*[-+])\s+").unwrap());
+
+/// Matches decimal digits outside of ASCII, such as Arabic-Indic or fullwidth digits.
+static NON_ASCII_DIGIT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[\p{Nd}--0-9]").unwrap());
+
+/// The marks that flag a digit as part of a repetend: the combining macron
+/// (U+0304), the combining overline (U+0305) and the overline (U+203E).
+const OVERLINES: [char; 3] = ['\u{304}', '\u{305}', '\u{203e}'];
+
+/// Returns `true` if every character of `s` may appear in a rational number string
+/// using the given digit grouping separators, and binary exponents if `binary_exp`.
+///
+/// This is a cheap pre-scan that lets obviously invalid inputs (hex literals, words,
+/// non-ASCII digits, ...) be rejected without running the regex. Passing this check
+/// does not mean the input is valid, only that the regex has to decide.
+fn has_valid_alphabet(s: &str, separators: &[char], binary_exp: bool) -> bool {
+    s.chars().all(|c| match c {
+        '0'..='9' | '+' | '-' | '.' | '/' | 'e' | 'E' => true,
+        'p' | 'P' => binary_exp,
+        // `\s` in the regex is Unicode-aware, so all whitespace must pass here.
+        c => c.is_whitespace() || separators.contains(&c),
+    })
+}
+
+/// Returns the error for an input that failed [`has_valid_alphabet`].
+///
+/// Non-ASCII decimal digits get a dedicated error, as they may be an attempt to
+/// spoof a number with look-alike characters.
+fn foreign_char_error(s: &str) -> ParseRatioError {
+    let kind = match NON_ASCII_DIGIT.find(s) {
+        Some(m) => RatioErrorKind::NonAsciiDigit {
+            position: m.start(),
+        },
+        None => RatioErrorKind::ParseError,
+    };
+    ParseRatioError::new(kind)
+}
+
+/// Parses a non-empty run of ASCII digits, returning `None` on overflow.
+fn parse_digits<T: FlexInteger>(digits: &str) -> Option<T> {
+    #[cfg(feature = "simd")]
+    if let Some(value) = simd::parse_long_digits(digits) {
+        return value;
+    }
+    T::from_str(digits).ok()
+}
+
+/// Integer types that a [`Ratio`] can be parsed into.
+///
+/// This trait is implemented automatically for every type satisfying its bounds,
+/// which includes all primitive signed integers and `num_bigint::BigInt`.
+pub trait FlexInteger:
+    Clone + Integer + Signed + FromStr + CheckedMul + CheckedAdd + CheckedSub + FromPrimitive
+{
+}
+
+impl<T> FlexInteger for T where
+    T: Clone + Integer + Signed + FromStr + CheckedMul + CheckedAdd + CheckedSub + FromPrimitive
 {
+}
+
+impl<T: FlexInteger> RationalParse for Ratio<T> {
     fn from_str_flex(input: &str) -> Result<Self, ParseRatioError> {
-        let cap = RATIONAL_FORMAT.captures(input).ok_or(ParseRatioError {
-            kind: RatioErrorKind::ParseError,
-        })?;
+        RationalParser::new().parse(input)
+    }
+}
 
-        let sign_str = cap.name("sign").map(|m| m.as_str()).unwrap_or("");
-        let num_str = cap.name("num").map(|m| m.as_str()).unwrap_or("");
-        let denom_str = cap.name("denom").map(|m| m.as_str());
-        let decimal_str = cap.name("decimal").map(|m| m.as_str());
-        let exp_str = cap.name("exp").map(|m| m.as_str());
+/// A configurable parser for rational number strings.
+///
+/// With the default configuration, `RationalParser` accepts exactly the formats
+/// of [`RationalParse::from_str_flex`]. The builder methods enable stricter
+/// validation or additional syntax.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, RationalParser};
+///
+/// let parser = RationalParser::new().require_sign(true);
+/// assert_eq!(parser.parse::<i32>("+3/2").unwrap(), Ratio::new(3, 2));
+/// assert_eq!(
+///     *parser.parse::<i32>("3/2").unwrap_err().kind(),
+///     RatioErrorKind::MissingSign
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct RationalParser {
+    require_sign: bool,
+    allow_percent: bool,
+    grouping_separators: Cow<'static, [char]>,
+    require_proper_fraction: bool,
+    allow_chained_division: bool,
+    allow_fraction_exponent: bool,
+    on_overflow: OverflowMode,
+    zero_denominator: ZeroDenomPolicy,
+    allow_binary_exponent: bool,
+    exponent_base: u32,
+    allow_signed_denominator: bool,
+    require_terminating_decimal: bool,
+    lenient_fraction: bool,
+    forbid_leading_zeros: bool,
+    allow_overline_repetend: bool,
+    allow_parenthesized_negative: bool,
+    preserve_trailing_zeros: bool,
+    allow_division_sign: bool,
+    allow_words: bool,
+    allow_empty_denominator: bool,
+    allow_implicit_mantissa: bool,
+    max_significant_digits: Option<(usize, ExcessDigits)>,
+    expected_decimal_digits: Option<usize>,
+    reduce_before_overflow: bool,
+    #[cfg(feature = "error-input")]
+    error_input_len: usize,
+}
 
-        // Validate "lookahead" equivalent
-        let num_has_digits = !num_str.is_empty();
-        let decimal_has_digits = decimal_str.is_some_and(|s| !s.is_empty());
+impl Default for RationalParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        if !num_has_digits && !decimal_has_digits {
-            return Err(ParseRatioError {
-                kind: RatioErrorKind::ParseError,
-            });
+impl RationalParser {
+    /// Creates a parser with the default configuration.
+    pub const fn new() -> Self {
+        RationalParser {
+            require_sign: false,
+            allow_percent: false,
+            grouping_separators: Cow::Borrowed(&['_']),
+            require_proper_fraction: false,
+            allow_chained_division: false,
+            allow_fraction_exponent: false,
+            on_overflow: OverflowMode::Error,
+            zero_denominator: ZeroDenomPolicy::Error,
+            allow_binary_exponent: false,
+            exponent_base: 10,
+            allow_signed_denominator: false,
+            require_terminating_decimal: false,
+            lenient_fraction: false,
+            forbid_leading_zeros: false,
+            allow_overline_repetend: false,
+            allow_parenthesized_negative: false,
+            preserve_trailing_zeros: false,
+            allow_division_sign: false,
+            allow_words: false,
+            allow_empty_denominator: false,
+            allow_implicit_mantissa: false,
+            max_significant_digits: None,
+            expected_decimal_digits: None,
+            reduce_before_overflow: false,
+            #[cfg(feature = "error-input")]
+            error_input_len: ParseRatioError::MAX_INPUT_LEN,
         }
+    }
 
-        let parse_val = |s: &str| -> Result<T, ParseRatioError> {
-            if s.is_empty() {
-                return Ok(T::zero());
-            }
-            if s.contains('_') {
-                let s_clean = s.replace('_', "");
-                T::from_str(&s_clean).map_err(|_| ParseRatioError {
-                    kind: RatioErrorKind::Overflow,
-                })
-            } else {
-                T::from_str(s).map_err(|_| ParseRatioError {
-                    kind: RatioErrorKind::Overflow,
-                })
-            }
-        };
+    /// Requires the input to start with an explicit `+` or `-` sign.
+    ///
+    /// Unsigned inputs such as `"3/2"` are rejected with
+    /// [`RatioErrorKind::MissingSign`]. Defaults to `false`.
+    pub fn require_sign(mut self, require: bool) -> Self {
+        self.require_sign = require;
+        self
+    }
 
-        let ten = T::from_u8(10).ok_or(ParseRatioError {
-            kind: RatioErrorKind::ParseError,
-        })?;
+    /// Accepts a trailing `%` sign, which divides the value by 100.
+    ///
+    /// With this enabled, `"50%"` parses to `1/2` and `"1.5e1 %"` to `3/20`.
+    /// Inputs without a `%` are parsed as usual. Defaults to `false`.
+    pub fn allow_percent(mut self, allow: bool) -> Self {
+        self.allow_percent = allow;
+        self
+    }
 
-        let checked_pow = |base: &T, exp: u32| -> Result<T, ParseRatioError> {
-            num_traits::checked_pow(base.clone(), exp as usize).ok_or(ParseRatioError {
-                kind: RatioErrorKind::Overflow,
-            })
-        };
+    /// Requires the parsed value to be a proper fraction, i.e. strictly between
+    /// -1 and 1.
+    ///
+    /// The check is made on the reduced value, so `"1/2"`, `"-0.5"` and `"0"` pass,
+    /// while `"3/2"`, `"5"` and `"2/2"` fail with
+    /// [`RatioErrorKind::ImproperFraction`]. Defaults to `false`.
+    pub fn require_proper_fraction(mut self, require: bool) -> Self {
+        self.require_proper_fraction = require;
+        self
+    }
 
-        let mut numerator: T = parse_val(num_str)?;
-        let mut denominator: T;
+    /// Requires the parsed value to have a terminating decimal expansion.
+    ///
+    /// This holds if the reduced denominator has no prime factors other than 2
+    /// and 5, so `"1/4"`, `"0.75"` and `"7/20"` pass, while `"1/3"` and `"5/6"`
+    /// fail with [`RatioErrorKind::NonTerminating`]. Defaults to `false`.
+    pub fn require_terminating_decimal(mut self, require: bool) -> Self {
+        self.require_terminating_decimal = require;
+        self
+    }
 
-        if let Some(d_str) = denom_str {
-            denominator = parse_val(d_str)?;
-        } else {
-            denominator = T::one();
-            if let Some(dec) = decimal_str {
-                // Strip trailing zeros to avoid unnecessary overflow and create more efficient rationals
-                // e.g., "1.0000000000" becomes "1.0" instead of creating denominator = 10^10
-                let dec_trimmed = dec.trim_end_matches('0');
-                let dec_clean_owned: String;
-                let dec_final = if dec_trimmed.contains('_') {
-                    dec_clean_owned = dec_trimmed.replace('_', "");
-                    &dec_clean_owned
-                } else {
-                    dec_trimmed
-                };
+    /// Accepts chained divisions such as `"1/2/3"`, evaluated left to right.
+    ///
+    /// Division is left-associative, so `"1/2/3"` is `(1/2)/3 = 1/6` and
+    /// `"8/4/2"` is `1`. Every term after the first slash must be an unsigned
+    /// integer. A zero divisor fails with [`RatioErrorKind::ZeroDenominator`].
+    /// Defaults to `false`, where more than one `/` is a parse error.
+    pub fn allow_chained_division(mut self, allow: bool) -> Self {
+        self.allow_chained_division = allow;
+        self
+    }
 
-                // Power of 10 equal to number of significant decimal digits
-                let scale = checked_pow(&ten, dec_final.len() as u32)?;
+    /// Accepts an exponent after a fraction, scaling the whole fraction.
+    ///
+    /// A positive exponent multiplies the numerator and a negative one the
+    /// denominator, so `"3/4e2"` is `300/4 = 75` and `"3/4e-1"` is `3/40`. With
+    /// [`allow_binary_exponent`](Self::allow_binary_exponent), `"3/4p2"` is
+    /// `3`. Powers of the base are cancelled against the other side first,
+    /// which keeps `"5/10e9"` within a `Ratio<i32>`. Defaults to `false`, where
+    /// an exponent after a fraction is a parse error.
+    pub fn allow_fraction_exponent(mut self, allow: bool) -> Self {
+        self.allow_fraction_exponent = allow;
+        self
+    }
 
-                let dec_val = if dec_final.is_empty() {
-                    T::zero()
-                } else {
-                    T::from_str(dec_final).map_err(|_| ParseRatioError {
-                        kind: RatioErrorKind::Overflow,
-                    })?
-                };
+    /// Accepts `p` or `P` as a base-2 exponent marker, e.g. `"3p4"` for `3 * 2^4`.
+    ///
+    /// The mantissa is still decimal, so `"1.5p-1"` is `1.5 / 2 = 3/4` and
+    /// `"3p-1"` is `3/2`. Only the exponent marker decides the base: `"3e4"`
+    /// remains `30000`. A marker without digits, as in `"3p"`, fails with
+    /// [`RatioErrorKind::DanglingExponent`]. Defaults to `false`, where a `p` is
+    /// a parse error.
+    pub fn allow_binary_exponent(mut self, allow: bool) -> Self {
+        self.allow_binary_exponent = allow;
+        self
+    }
 
-                numerator = numerator
-                    .checked_mul(&scale)
-                    .ok_or(ParseRatioError {
-                        kind: RatioErrorKind::Overflow,
-                    })?
-                    .checked_add(&dec_val)
-                    .ok_or(ParseRatioError {
-                        kind: RatioErrorKind::Overflow,
-                    })?;
-
-                denominator = denominator.checked_mul(&scale).ok_or(ParseRatioError {
-                    kind: RatioErrorKind::Overflow,
-                })?;
-            }
-            if let Some(exp_s) = exp_str {
-                let exp_clean_owned: String;
-                let exp_final = if exp_s.contains('_') {
-                    exp_clean_owned = exp_s.replace('_', "");
-                    &exp_clean_owned
-                } else {
-                    exp_s
-                };
-                let exp_val = exp_final.parse::<i32>().map_err(|_| ParseRatioError {
-                    kind: RatioErrorKind::ParseError,
-                })?;
+    /// Accepts a trailing `/` without a denominator, which then defaults to one.
+    ///
+    /// With this enabled, `"3/"` is `3`, and so are `"3 /"` and `"3/ "`, as the
+    /// usual whitespace around the `/` and at the end is still accepted. The
+    /// slash must follow a numerator and end the number: `"/"`, `"3/-"` and
+    /// `"3/e2"` remain parse errors. Defaults to `false`.
+    pub fn allow_empty_denominator(mut self, allow: bool) -> Self {
+        self.allow_empty_denominator = allow;
+        self
+    }
 
-                let abs_exp = exp_val.unsigned_abs();
-                let scale = checked_pow(&ten, abs_exp)?;
+    /// Sets how many bytes of the input a failed parse records in its error,
+    /// see [`ParseRatioError::input`].
+    ///
+    /// The input is stored inline so that the error stays `Copy`, which caps
+    /// the length at [`ParseRatioError::MAX_INPUT_LEN`], the default; larger
+    /// values keep that many bytes. A length of zero records no input, so
+    /// `input()` returns `None`.
+    ///
+    /// Requires the `error-input` feature.
+    #[cfg(feature = "error-input")]
+    pub fn error_input_len(mut self, len: usize) -> Self {
+        self.error_input_len = len;
+        self
+    }
 
-                if exp_val >= 0 {
-                    numerator = numerator.checked_mul(&scale).ok_or(ParseRatioError {
-                        kind: RatioErrorKind::Overflow,
-                    })?;
-                } else {
-                    denominator = denominator.checked_mul(&scale).ok_or(ParseRatioError {
-                        kind: RatioErrorKind::Overflow,
-                    })?;
-                }
-            }
-        }
+    /// Accepts an exponent without a mantissa, which then defaults to one, so
+    /// `"e5"` is `100000` and `"e-3"` is `1/1000`.
+    ///
+    /// A sign before the marker is the sign of the implicit mantissa: `"-e3"` is
+    /// `-1000`, and [`require_sign`](Self::require_sign) accepts `"+e3"` but not
+    /// `"e3"`. The exponent is applied as usual, including its base and the
+    /// overflow checks, so `"e10"` overflows a `Ratio<i32>`. Only a bare
+    /// exponent is affected: `".e5"`, `"/e5"` and `"e"` remain errors. Defaults
+    /// to `false`, where a missing mantissa is a parse error.
+    pub fn allow_implicit_mantissa(mut self, allow: bool) -> Self {
+        self.allow_implicit_mantissa = allow;
+        self
+    }
 
-        if sign_str == "-" {
-            numerator = -numerator;
-        }
+    /// Sets the base that an `e` exponent scales by, e.g. 2 to read `"3e4"` as
+    /// `3 * 2^4 = 48`.
+    ///
+    /// Only the exponent changes base: the mantissa is still decimal, so with a
+    /// base of 2, `"1.5e-1"` is `1.5 / 2 = 3/4`. This is valid but unusual, and
+    /// the decimal scale of the mantissa can then no longer cancel against the
+    /// exponent, so large scales overflow sooner than with base 10. With
+    /// [`allow_binary_exponent`](Self::allow_binary_exponent), a `p` exponent
+    /// remains base 2. Defaults to 10.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is less than 2.
+    pub fn exponent_base(mut self, base: u32) -> Self {
+        assert!(base >= 2, "exponent base must be at least 2");
+        self.exponent_base = base;
+        self
+    }
 
-        if denominator.is_zero() {
-            return Err(ParseRatioError {
-                kind: RatioErrorKind::ZeroDenominator,
-            });
+    /// Accepts a `+` or `-` sign directly before the denominator of a fraction.
+    ///
+    /// The signs of the numerator and the denominator are combined, so `"3/-2"`
+    /// is `-3/2` and `"-3/-2"` is `3/2`. [`require_sign`](Self::require_sign)
+    /// still only looks at the sign of the numerator. Defaults to `false`, where
+    /// a signed denominator is a parse error.
+    pub fn allow_signed_denominator(mut self, allow: bool) -> Self {
+        self.allow_signed_denominator = allow;
+        self
+    }
+
+    /// Accepts whitespace between a sign and the digits it applies to.
+    ///
+    /// With this enabled, `"- 3"` and `" - 3 / 2 "` are accepted; together with
+    /// [`allow_signed_denominator`](Self::allow_signed_denominator), so is
+    /// `" - 3 / - 2 "`. The whitespace is removed before parsing. This does not
+    /// extend to exponents: `"1e- 3"` remains invalid. Whitespace around the
+    /// `/` and around the whole number is always accepted. Defaults to `false`.
+    pub fn lenient_fraction(mut self, lenient: bool) -> Self {
+        self.lenient_fraction = lenient;
+        self
+    }
+
+    /// Rejects integer parts, denominators and exponents with leading zeros.
+    ///
+    /// A digit group that starts with `0` and has further digits, like `"007"`,
+    /// `"3/04"` or `"1e05"`, fails with [`RatioErrorKind::LeadingZero`]. A lone
+    /// zero is fine, as are zeros after the decimal point, so `"0"`, `"0.05"`,
+    /// `"3/10"` and `"1e0"` are accepted. Digit grouping counts as part of the
+    /// group: `"0_1"` has a leading zero. Defaults to `false`.
+    pub fn forbid_leading_zeros(mut self, forbid: bool) -> Self {
+        self.forbid_leading_zeros = forbid;
+        self
+    }
+
+    /// Accepts repeating decimals whose repetend is marked with overlines.
+    ///
+    /// A digit followed by a combining macron (U+0304), a combining overline
+    /// (U+0305) or an overline (U+203E) repeats. Every digit of the repetend
+    /// carries its own mark, and the marked digits must be the last digits of
+    /// the fractional part, so `"0.3̅"` and `"0.3‾"` are `1/3`, `"0.16̅"` is
+    /// `1/6` and `"1.1̅4̅2̅8̅5̅7̅"` is `8/7`. A marked digit before an unmarked
+    /// one, an integer digit with a mark, doubled marks and exponents are parse
+    /// errors. Defaults to `false`, where the marks are parse errors.
+    pub fn allow_overline_repetend(mut self, allow: bool) -> Self {
+        self.allow_overline_repetend = allow;
+        self
+    }
+
+    /// Accepts accounting-style negatives wrapped in parentheses.
+    ///
+    /// If the whole input (after trimming whitespace) is enclosed in a pair of
+    /// parentheses, they are read as a minus sign: `"(3/4)"` is `-3/4` and
+    /// `"(1.5)"` is `-3/2`. The parenthesized number may not have a sign of its
+    /// own, so `"(-3/4)"` and `"(+3/4)"` are parse errors, as are unbalanced or
+    /// nested parentheses. The parentheses satisfy
+    /// [`require_sign`](Self::require_sign). Defaults to `false`, where any
+    /// parenthesis is a parse error.
+    pub fn allow_parenthesized_negative(mut self, allow: bool) -> Self {
+        self.allow_parenthesized_negative = allow;
+        self
+    }
+
+    /// Accepts the division sign `÷` (U+00F7) in place of `/`.
+    ///
+    /// Every `÷` is treated exactly like a `/`, including the whitespace allowed
+    /// around it, so `"3÷4"` and `"3 ÷ 4"` are `3/4`. Defaults to `false`, where a
+    /// `÷` is a parse error.
+    pub fn allow_division_sign(mut self, allow: bool) -> Self {
+        self.allow_division_sign = allow;
+        self
+    }
+
+    /// Accepts the English words of rate expressions: `per` as a fraction
+    /// separator, and a trailing `percent` or `per cent` as a `%` sign.
+    ///
+    /// With this enabled, `"3 per 4"` is `3/4` and `"5 per cent"` is `1/20`,
+    /// even without [`allow_percent`](Self::allow_percent). Words are matched
+    /// case-insensitively, with any whitespace around them, so `"5 PerCent"`
+    /// and `"3per4"` are accepted too. The result must still be a valid number:
+    /// `"per 4"`, `"3 per"` and `"5 percent percent"` are parse errors.
+    ///
+    /// Mixed numbers joined by `and` are accepted as well: `"1 and 3/4"` (or
+    /// `"1 and 3 per 4"`) is `7/4`, and `"-1 and 3/4"` is `-7/4`. The whole part
+    /// must be an integer and the fraction a proper, unsigned `n/d`; anything
+    /// else, like `"1 and 5/4"` or `"1.5 and 1/2"`, is a parse error. Numbers
+    /// and fractions spelled out in words, like `"one and three quarters"`, are
+    /// not supported. Defaults to `false`, where any word is a parse error.
+    pub fn allow_words(mut self, allow: bool) -> Self {
+        self.allow_words = allow;
+        self
+    }
+
+    /// Keeps the written scale of decimals instead of reducing them.
+    ///
+    /// A decimal is returned unreduced, as its digits over the power of ten given
+    /// by its scale: `"1.50"` is `150/100`, `"1.5"` is `15/10`, `"1200"` is
+    /// `1200/1` and `"1.50e1"` is `150/10`. [`Ratio::numer`] and
+    /// [`Ratio::denom`] then carry the precision the number was written with,
+    /// and comparisons still treat the result as equal to the reduced value.
+    /// Zero is always `0/1`, and fractions like `"6/8"` are reduced as usual, as
+    /// are results of [`allow_percent`](Self::allow_percent) and
+    /// [`OverflowMode::NearestRepresentable`]. Arithmetic on the result reduces
+    /// it again.
+    ///
+    /// By default, trailing zeros are stripped before the numerator and
+    /// denominator are built, so `"1.0000000000"` fits in a `Ratio<i32>`. With
+    /// this enabled every written digit counts towards overflow, and that input
+    /// fails with [`RatioErrorKind::Overflow`]. Trailing zeros also count as
+    /// significant for [`max_significant_digits`](Self::max_significant_digits).
+    /// Defaults to `false`.
+    pub fn preserve_trailing_zeros(mut self, preserve: bool) -> Self {
+        self.preserve_trailing_zeros = preserve;
+        self
+    }
+
+    /// Limits decimals to `limit` significant digits.
+    ///
+    /// Significant digits are counted over the integer and fractional digits of
+    /// a decimal, ignoring leading zeros, trailing zeros of the fraction, and
+    /// trailing zeros of an integer without a fraction. So `"0.00120"`, `"1200"`
+    /// and `"1.2e10"` all have 2, and `"100.5"` has 4. Fractions such as
+    /// `"1/3"` are not limited.
+    ///
+    /// With [`ExcessDigits::Error`], more digits fail with
+    /// [`RatioErrorKind::TooManyDigits`]. With [`ExcessDigits::Round`], the value
+    /// is rounded half to even to `limit` digits: `"3.14159"` with a limit of 3
+    /// is `3.14`. Since the extra digits are dropped before the numerator is
+    /// built, over-precise input cannot overflow the target type. Unlimited by
+    /// default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    pub fn max_significant_digits(mut self, limit: usize, excess: ExcessDigits) -> Self {
+        assert!(limit > 0, "significant digit limit must be positive");
+        self.max_significant_digits = Some((limit, excess));
+        self
+    }
+
+    /// Hints that decimals have up to `digits` digits, so that values which only
+    /// fit in `T` once reduced can be parsed.
+    ///
+    /// A decimal is built as its digits over a power of ten before it is
+    /// reduced, so `"0.00000095367431640625"` (`1/1048576`) needs a denominator
+    /// of `10^20` on the way and overflows a `Ratio<i32>`. With this hint, an
+    /// input that overflows `T` is parsed again in a wider intermediate type,
+    /// and the reduced value is narrowed to `T`, failing with
+    /// [`RatioErrorKind::Overflow`] only if it still does not fit.
+    ///
+    /// Up to 18 digits, the intermediate type is `i64`, and beyond that it is
+    /// `i128`, which holds powers of ten up to `10^38`. Larger hints also use
+    /// `i128`. The hint has no effect if `T` is at least as wide as the
+    /// intermediate type, and inputs that fit in `T` are parsed only once. No
+    /// hint is set by default.
+    pub fn expected_decimal_digits(mut self, digits: usize) -> Self {
+        self.expected_decimal_digits = Some(digits);
+        self
+    }
+
+    /// Reduces fractions before checking that they fit in `T`.
+    ///
+    /// By default, the numerator and denominator of `"2000000000/4000000000"`
+    /// must each fit in `T` before the fraction is reduced, so it overflows a
+    /// `Ratio<i32>`. With this enabled, a fraction that overflows `T` is parsed
+    /// again in `i128`, and the reduced value, here `1/2`, is narrowed to `T`.
+    /// It fails with [`RatioErrorKind::Overflow`] only if the reduced value does
+    /// not fit, or if a part does not even fit in `i128`. This applies to every
+    /// input with a `/`; see
+    /// [`expected_decimal_digits`](Self::expected_decimal_digits) for decimals.
+    /// Defaults to `false`.
+    pub fn reduce_before_overflow(mut self, reduce: bool) -> Self {
+        self.reduce_before_overflow = reduce;
+        self
+    }
+
+    /// Sets how to handle a value that cannot be represented exactly in `T`.
+    ///
+    /// With [`OverflowMode::NearestRepresentable`], `"0.123456789012345"` parsed
+    /// into a `Ratio<i32>` is `115226266/933332763` instead of an error. See
+    /// [`OverflowMode`] for the details. Defaults to [`OverflowMode::Error`].
+    pub fn on_overflow(mut self, mode: OverflowMode) -> Self {
+        self.on_overflow = mode;
+        self
+    }
+
+    /// Sets how to handle a fraction with a zero denominator, like `"1/0"`.
+    ///
+    /// With [`ZeroDenomPolicy::Saturate`], such a fraction is replaced by a
+    /// large sentinel integer instead of an error. This loses information; see
+    /// [`ZeroDenomPolicy`] for the details. Defaults to
+    /// [`ZeroDenomPolicy::Error`].
+    pub fn zero_denominator_policy(mut self, policy: ZeroDenomPolicy) -> Self {
+        self.zero_denominator = policy;
+        self
+    }
+
+    /// Sets the character used to group digits, e.g. `,` for `"1,000,000"`.
+    ///
+    /// The separator may only appear between two digits, exactly like the
+    /// default `_`. Only one separator is recognized at a time: with `Some(',')`,
+    /// underscores are rejected. `None` disables digit grouping entirely.
+    /// The decimal separator is always `.`. Defaults to `Some('_')`.
+    ///
+    /// To accept several separators, use
+    /// [`grouping_separators`](Self::grouping_separators).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the separator is a digit, whitespace, or one of the characters
+    /// `+-./eE` that already have a meaning in the grammar.
+    pub fn grouping_separator(self, separator: Option<char>) -> Self {
+        self.grouping_separators(separator.as_slice())
+    }
+
+    /// Sets the characters that may be used to group digits.
+    ///
+    /// With `&[',', '_']`, both `"1,000"` and `"1_000"` are accepted. Each
+    /// separator follows the rules of
+    /// [`grouping_separator`](Self::grouping_separator), and a single input may
+    /// only use one of them: `"1,000_000"` and `"1,000/2_000"` are parse errors,
+    /// as mixed separators usually mean that values were mangled or joined. An
+    /// empty slice disables digit grouping. Defaults to `&['_']`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any separator is a digit, whitespace, or one of the characters
+    /// `+-./eE` that already have a meaning in the grammar.
+    pub fn grouping_separators(mut self, separators: &[char]) -> Self {
+        for &c in separators {
+            assert!(
+                !(c.is_ascii_digit() || c.is_whitespace() || "+-./eE".contains(c)),
+                "invalid grouping separator {:?}",
+                c
+            );
         }
+        self.grouping_separators = Cow::Owned(separators.to_vec());
+        self
+    }
 
-        Ok(Ratio::new(numerator, denominator))
+    /// Parses a string into a rational number using this configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseRatioError`] under the same conditions as
+    /// [`RationalParse::from_str_flex`], or if the input violates one of the
+    /// restrictions enabled on this parser.
+    ///
+    /// With the `tracing` feature, every failure is also reported as a `debug`
+    /// event carrying the input (cut to 64 characters) and the error kind. With
+    /// the `error-input` feature, the error records the input itself; see
+    /// `ParseRatioError::input`.
+    pub fn parse<T: FlexInteger>(&self, input: &str) -> Result<Ratio<T>, ParseRatioError> {
+        self.parse_value(input)
+            .and_then(|value| self.check_value(&value).map(|()| value))
+            .map_err(|e| self.report_failure(input, e))
+    }
+
+    /// Finishes the error of a failed parse of the caller's `input`: it is
+    /// traced with the `tracing` feature, and records the input with
+    /// `error-input`.
+    #[cfg_attr(
+        not(any(feature = "tracing", feature = "error-input")),
+        allow(unused_variables)
+    )]
+    fn report_failure(&self, input: &str, e: ParseRatioError) -> ParseRatioError {
+        #[cfg(feature = "tracing")]
+        trace_failure(input, &e);
+        #[cfg(feature = "error-input")]
+        let e = ParseRatioError {
+            input: (self.error_input_len > 0)
+                .then(|| InputSnippet::new(input, self.error_input_len)),
+            ..e
+        };
+        e
     }
+
+    /// Parses a string like [`parse`](Self::parse), and reports how the value
+    /// was made to fit in `T`.
+    ///
+    /// This is meant for auditing whether `T` is wide enough for the data: with
+    /// [`reduce_before_overflow`](Self::reduce_before_overflow) or
+    /// [`expected_decimal_digits`](Self::expected_decimal_digits), an input
+    /// that only fits once reduced succeeds silently with [`parse`](Self::parse),
+    /// but sets [`ParseReport::reduced_to_fit`] here.
+    ///
+    /// The check parses the input again without the widening options, so it
+    /// costs up to two extra parses if one of them is enabled, and none
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseRatioError`] under the same conditions as
+    /// [`parse`](Self::parse).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use num_rational_parse::RationalParser;
+    ///
+    /// let parser = RationalParser::new().reduce_before_overflow(true);
+    /// let (value, report) = parser.parse_with_report::<i32>("2000000000/4000000000").unwrap();
+    /// assert_eq!(value, Ratio::new(1, 2));
+    /// assert!(report.reduced_to_fit);
+    ///
+    /// let (_, report) = parser.parse_with_report::<i32>("1/2").unwrap();
+    /// assert!(!report.reduced_to_fit);
+    /// ```
+    pub fn parse_with_report<T: FlexInteger>(
+        &self,
+        input: &str,
+    ) -> Result<(Ratio<T>, ParseReport), ParseRatioError> {
+        let value = self.parse(input)?;
+        let widening = self.reduce_before_overflow || self.expected_decimal_digits.is_some();
+        let reduced_to_fit = widening && {
+            let exact = RationalParser {
+                on_overflow: OverflowMode::Error,
+                ..self.clone()
+            };
+            let strict = RationalParser {
+                reduce_before_overflow: false,
+                expected_decimal_digits: None,
+                ..exact.clone()
+            };
+            // Widening only helps where the strict parse overflows; the value
+            // may still come from an approximation if widening did not help.
+            strict
+                .parse::<T>(input)
+                .is_err_and(|e| e.kind == RatioErrorKind::Overflow)
+                && exact.parse::<T>(input).is_ok()
+        };
+        Ok((value, ParseReport { reduced_to_fit }))
+    }
+
+    /// Parses the input, including any optional suffixes, without validating the result.
+    fn parse_value<T: FlexInteger>(&self, input: &str) -> Result<Ratio<T>, ParseRatioError> {
+        // The rewrites below shift byte offsets, so a non-ASCII digit is located
+        // in the caller's input first.
+        self.check_ascii_digits(input)?;
+        let (worded, divided, negated);
+        let mut input = input;
+        let mut allow_percent = self.allow_percent;
+        if self.allow_words {
+            let (rest, percent_word) = match PERCENT_WORD.find(input) {
+                Some(m) => (&input[..m.start()], "%"),
+                None => (input, ""),
+            };
+            worded = PER_WORD.replace_all(rest, "/") + percent_word;
+            allow_percent |= !percent_word.is_empty();
+            input = &worded;
+        }
+        if self.allow_division_sign && input.contains('÷') {
+            divided = input.replace('÷', "/");
+            input = &divided;
+        }
+        if self.allow_parenthesized_negative {
+            let trimmed = input.trim();
+            if let Some(inner) = trimmed.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+                let inner = inner.trim_start();
+                if inner.starts_with(['-', '+']) {
+                    return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+                }
+                // Parsing with an explicit sign keeps values like `i32::MIN`, whose
+                // magnitude does not fit, and satisfies `require_sign`.
+                negated = format!("-{inner}");
+                input = &negated;
+            }
+        }
+        if allow_percent {
+            if let Some(rest) = input.trim_end().strip_suffix('%') {
+                return percent_to_ratio(self.parse_term(rest)?);
+            }
+        }
+        self.parse_term(input)
+    }
+
+    /// Fails with [`RatioErrorKind::NonAsciiDigit`] at the first non-ASCII
+    /// decimal digit of `input` that is not a grouping separator.
+    fn check_ascii_digits(&self, input: &str) -> Result<(), ParseRatioError> {
+        if input.is_ascii() {
+            return Ok(());
+        }
+        let separator = |m: &regex::Match| {
+            m.as_str()
+                .chars()
+                .next()
+                .is_some_and(|c| self.grouping_separators.contains(&c))
+        };
+        match NON_ASCII_DIGIT.find_iter(input).find(|m| !separator(m)) {
+            Some(m) => Err(ParseRatioError::new(RatioErrorKind::NonAsciiDigit {
+                position: m.start(),
+            })),
+            None => Ok(()),
+        }
+    }
+
+    /// Parses a plain rational number or, if words are allowed, a mixed number
+    /// like `"1 and 3/4"`.
+    fn parse_term<T: FlexInteger>(&self, input: &str) -> Result<Ratio<T>, ParseRatioError> {
+        if !self.allow_words {
+            return self.parse_core(input);
+        }
+        let Some(cap) = MIXED_NUMBER.captures(input) else {
+            return self.parse_core(input);
+        };
+        let whole = &cap["whole"];
+        let whole_value = self.parse_core::<T>(whole)?;
+        // The fraction has no sign of its own.
+        let fraction = RationalParser {
+            require_sign: false,
+            ..self.clone()
+        }
+        .parse_core::<T>(&cap["fraction"])?;
+        if fraction >= Ratio::one() {
+            return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+        }
+        let value = if whole.starts_with('-') {
+            whole_value.checked_sub(&fraction)
+        } else {
+            whole_value.checked_add(&fraction)
+        };
+        value.ok_or(ParseRatioError::new(RatioErrorKind::Overflow))
+    }
+
+    /// Checks a parsed value against the restrictions enabled on this parser.
+    fn check_value<T: FlexInteger>(&self, value: &Ratio<T>) -> Result<(), ParseRatioError> {
+        if self.require_proper_fraction {
+            // Compare against the denominator instead of taking the absolute value
+            // of the numerator, which could overflow.
+            let denom = value.denom();
+            if *value.numer() >= *denom || *value.numer() <= -denom.clone() {
+                return Err(ParseRatioError::new(RatioErrorKind::ImproperFraction));
+            }
+        }
+        if self.require_terminating_decimal && !has_terminating_decimal(value) {
+            return Err(ParseRatioError::new(RatioErrorKind::NonTerminating));
+        }
+        Ok(())
+    }
+
+    /// Rewrites the grouping separator used by `input` to the `_` the grammar
+    /// expects, failing if it uses more than one of the configured separators.
+    fn normalize_grouping<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ParseRatioError> {
+        let mut used = self
+            .grouping_separators
+            .iter()
+            .filter(|&&c| input.contains(c));
+        match (used.next(), used.next()) {
+            (Some(_), Some(_)) => Err(ParseRatioError::new(RatioErrorKind::ParseError)),
+            (Some(&c), None) if c != '_' => Ok(Cow::Owned(input.replace(c, "_"))),
+            _ => Ok(Cow::Borrowed(input)),
+        }
+    }
+
+    /// Parses a plain rational number, without any of the optional suffixes.
+    fn parse_core<T: FlexInteger>(&self, input: &str) -> Result<Ratio<T>, ParseRatioError> {
+        let result = match self.parse_exact(input) {
+            Err(e) if e.kind == RatioErrorKind::Overflow => {
+                if self.reduce_before_overflow && input.contains('/') {
+                    self.parse_widened(input, true)
+                } else if let Some(digits) = self.expected_decimal_digits {
+                    self.parse_widened(input, digits > 18)
+                } else {
+                    Err(e)
+                }
+            }
+            result => result,
+        };
+        match result {
+            Err(e)
+                if e.kind == RatioErrorKind::Overflow
+                    && self.on_overflow == OverflowMode::NearestRepresentable =>
+            {
+                let exact = self.parse_exact::<i128>(input)?;
+                nearest_representable(&exact).ok_or(ParseRatioError::new(RatioErrorKind::Overflow))
+            }
+            result => result,
+        }
+    }
+
+    /// Parses a plain rational number in `i128` if `wide`, or in `i64`
+    /// otherwise, then narrows the reduced value to `T`.
+    fn parse_widened<T: FlexInteger>(
+        &self,
+        input: &str,
+        wide: bool,
+    ) -> Result<Ratio<T>, ParseRatioError> {
+        let (numer, denom) = if wide {
+            let (numer, denom) = self.parse_exact::<i128>(input)?.into_raw();
+            (T::from_i128(numer), T::from_i128(denom))
+        } else {
+            let (numer, denom) = self.parse_exact::<i64>(input)?.into_raw();
+            (T::from_i64(numer), T::from_i64(denom))
+        };
+        let numer = numer.ok_or(ParseRatioError::overflow_in(OverflowSource::Numerator))?;
+        let denom = denom.ok_or(ParseRatioError::overflow_in(OverflowSource::Denominator))?;
+        // The sign and reduction, or the preserved scale, carry over unchanged.
+        Ok(Ratio::new_raw(numer, denom))
+    }
+
+    /// Parses a plain rational number, failing if it does not fit in `T`.
+    fn parse_exact<T: FlexInteger>(&self, input: &str) -> Result<Ratio<T>, ParseRatioError> {
+        if self.allow_overline_repetend && input.contains(OVERLINES) {
+            return self.parse_repetend(input);
+        }
+        if self.allow_chained_division {
+            if let Some((head, divisors)) = split_chained_division(input) {
+                return self.parse_chained_division(head, divisors);
+            }
+        }
+        let (numerator, denominator) = self.parse_raw(input)?;
+        // Only decimals have a scale to preserve. Their denominator is always
+        // positive, so the raw ratio is otherwise well-formed.
+        if self.preserve_trailing_zeros && !input.contains('/') {
+            return Ok(Ratio::new_raw(numerator, denominator));
+        }
+        Ok(Ratio::new(numerator, denominator))
+    }
+
+    /// Parses a decimal whose trailing digits are marked as repeating.
+    fn parse_repetend<T: FlexInteger>(&self, input: &str) -> Result<Ratio<T>, ParseRatioError> {
+        let invalid = ParseRatioError::new(RatioErrorKind::ParseError);
+
+        // Remove the marks, remembering which range of `plain` they covered.
+        let mut plain = String::with_capacity(input.len());
+        let mut marked: Option<(usize, usize)> = None;
+        let mut in_fraction = false;
+        for c in input.trim().chars() {
+            if OVERLINES.contains(&c) {
+                if !in_fraction || !plain.ends_with(|p: char| p.is_ascii_digit()) {
+                    return Err(invalid);
+                }
+                let digit = plain.len() - 1;
+                marked = match marked {
+                    None => Some((digit, plain.len())),
+                    Some((start, end)) if end == digit => Some((start, plain.len())),
+                    // A gap between marked digits, or a digit marked twice.
+                    Some(_) => return Err(invalid),
+                };
+            } else if marked.is_some() && !c.is_ascii_digit() {
+                return Err(invalid);
+            } else {
+                in_fraction |= c == '.';
+                plain.push(c);
+            }
+        }
+        // The repetend has to end the number.
+        let (start, end) = marked
+            .filter(|&(_, end)| end == plain.len())
+            .ok_or(invalid)?;
+        let (head, repetend) = (&plain[..start], &plain[start..end]);
+
+        // value = head + repetend / ((10^r - 1) * 10^k), for `r` repeating and
+        // `k` non-repeating fractional digits. A zero is appended to the head
+        // so that forms like ".3̅" still have a digit.
+        let (numerator, denominator) = self.parse_raw::<T>(&format!("{head}0"))?;
+        let head_value = Ratio::new(numerator, denominator);
+        let fraction_digits = head
+            .rsplit('.')
+            .next()
+            .map_or(0, |f| f.bytes().filter(u8::is_ascii_digit).count());
+        let overflow = ParseRatioError::new(RatioErrorKind::Overflow);
+        let ten = T::from_u8(10).ok_or(overflow)?;
+        let power = |exp: usize| num_traits::checked_pow(ten.clone(), exp).ok_or(overflow);
+        let nines = power(repetend.len())?
+            .checked_sub(&T::one())
+            .ok_or(overflow)?;
+        let scale = nines
+            .checked_mul(&power(fraction_digits)?)
+            .ok_or(overflow)?;
+        let tail = Ratio::new(T::from_str(repetend).map_err(|_| overflow)?, scale);
+        let value = if head.trim_start().starts_with('-') {
+            head_value.checked_sub(&tail)
+        } else {
+            head_value.checked_add(&tail)
+        };
+        value.ok_or(overflow)
+    }
+
+    /// Parses `head` as a fraction and divides it by each `/`-separated integer
+    /// in `divisors`, from left to right.
+    fn parse_chained_division<T: FlexInteger>(
+        &self,
+        head: &str,
+        divisors: &str,
+    ) -> Result<Ratio<T>, ParseRatioError> {
+        let (numerator, denominator) = self.parse_raw(head)?;
+        let mut value = Ratio::new(numerator, denominator);
+        for divisor in divisors.split('/') {
+            // Divisors are plain unsigned integers, like the denominator of a
+            // fraction, so only digits and separators may appear between the
+            // surrounding whitespace. Their placement is checked by `parse_raw`.
+            let digits = divisor.trim();
+            if !digits.starts_with(|c: char| c.is_ascii_digit())
+                || !digits
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || self.grouping_separators.contains(&c))
+            {
+                return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+            }
+            let (divisor, _) = self.parse_raw::<T>(divisor)?;
+            if divisor.is_zero() {
+                return Err(ParseRatioError::new(RatioErrorKind::ZeroDenominator));
+            }
+            value = value
+                .checked_div(&Ratio::from_integer(divisor))
+                .ok_or(ParseRatioError::new(RatioErrorKind::Overflow))?;
+        }
+        Ok(value)
+    }
+
+    /// Parses a plain rational number into its unreduced numerator and denominator.
+    ///
+    /// The denominator is guaranteed to be non-zero.
+    fn parse_raw<T: FlexInteger>(&self, input: &str) -> Result<(T, T), ParseRatioError> {
+        if !has_valid_alphabet(input, &self.grouping_separators, self.allow_binary_exponent) {
+            return Err(foreign_char_error(input));
+        }
+
+        let input = self.normalize_grouping(input)?;
+        let input = if self.lenient_fraction {
+            match SIGN_SPACE.replace_all(&input, "$sign") {
+                Cow::Borrowed(_) => input,
+                Cow::Owned(collapsed) => Cow::Owned(collapsed),
+            }
+        } else {
+            input
+        };
+        let input = input.as_ref();
+
+        let cap = RATIONAL_FORMAT.captures(input).ok_or_else(|| {
+            let kind = if MIXED_FRACTION_DECIMAL.is_match(input) {
+                RatioErrorKind::MixedFractionDecimal
+            } else {
+                RatioErrorKind::ParseError
+            };
+            ParseRatioError::new(kind)
+        })?;
+
+        let sign_str = cap.name("sign").map(|m| m.as_str()).unwrap_or("");
+        let num_str = cap.name("num").map(|m| m.as_str()).unwrap_or("");
+        let denom_str = cap.name("denom").map(|m| m.as_str());
+        let denom_sign = cap.name("denom_sign").map_or("", |m| m.as_str());
+        let decimal_str = cap.name("decimal").map(|m| m.as_str());
+        let exp_str = cap.name("exp").map(|m| m.as_str());
+        let binary_exp = cap
+            .name("marker")
+            .is_some_and(|m| m.as_str().eq_ignore_ascii_case("p"));
+
+        if !denom_sign.is_empty() && !self.allow_signed_denominator {
+            return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+        }
+
+        // A slash without a denominator stands for `/1` if allowed, but only
+        // at the very end of the number.
+        let denom_str = match denom_str {
+            Some("")
+                if self.allow_empty_denominator && denom_sign.is_empty() && exp_str.is_none() =>
+            {
+                None
+            }
+            Some("") => return Err(ParseRatioError::new(RatioErrorKind::ParseError)),
+            denom_str => denom_str,
+        };
+
+        if denom_str.is_some() && exp_str.is_some() && !self.allow_fraction_exponent {
+            return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+        }
+
+        if self.require_sign && sign_str.is_empty() {
+            return Err(ParseRatioError::new(RatioErrorKind::MissingSign));
+        }
+
+        // An exponent on its own scales an implicit mantissa of one, if allowed.
+        let num_str = if num_str.is_empty()
+            && self.allow_implicit_mantissa
+            && exp_str.is_some()
+            && decimal_str.is_none()
+            && denom_str.is_none()
+        {
+            "1"
+        } else {
+            num_str
+        };
+
+        // Validate "lookahead" equivalent
+        let num_has_digits = !num_str.is_empty();
+        let decimal_has_digits = decimal_str.is_some_and(|s| !s.is_empty());
+
+        if !num_has_digits && !decimal_has_digits {
+            return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+        }
+
+        // The regex accepts an exponent marker without digits so that this
+        // common typo can be reported precisely.
+        if exp_str.is_some_and(|s| s.trim_start_matches(['+', '-']).is_empty()) {
+            return Err(ParseRatioError::new(RatioErrorKind::DanglingExponent));
+        }
+
+        if self.forbid_leading_zeros {
+            let exp_digits = exp_str.map(|s| s.trim_start_matches(['+', '-']));
+            let has_leading_zero = |s: &str| s.len() > 1 && s.starts_with('0');
+            if [Some(num_str), denom_str, exp_digits]
+                .into_iter()
+                .flatten()
+                .any(has_leading_zero)
+            {
+                return Err(ParseRatioError::new(RatioErrorKind::LeadingZero));
+            }
+        }
+
+        // The signs of a fraction combine, so that the denominator stays positive.
+        // The numerator is built with its sign from the start: the magnitude of
+        // the most negative value, like 128 for `i8`, does not fit in `T`.
+        let negative = (sign_str == "-") != (denom_sign == "-");
+
+        let parse_val = |s: &str, negative: bool| -> Result<T, ParseRatioError> {
+            // Leading zeros (and separators between them) carry no value, so skip
+            // them cheaply instead of handing them to `T::from_str`.
+            let s = s.trim_start_matches(['0', '_']);
+            if s.is_empty() {
+                return Ok(T::zero());
+            }
+            let digits = if s.contains('_') {
+                Cow::Owned(s.replace('_', ""))
+            } else {
+                Cow::Borrowed(s)
+            };
+            match parse_digits::<T>(&digits) {
+                Some(value) if negative => Ok(-value),
+                Some(value) => Ok(value),
+                None if negative => T::from_str(&format!("-{digits}"))
+                    .map_err(|_| ParseRatioError::new(RatioErrorKind::Overflow)),
+                None => Err(ParseRatioError::new(RatioErrorKind::Overflow)),
+            }
+        };
+
+        let ten = T::from_u8(10).ok_or(ParseRatioError::new(RatioErrorKind::ParseError))?;
+
+        let checked_pow = |base: &T, exp: u32| -> Option<T> {
+            num_traits::checked_pow(base.clone(), exp as usize)
+        };
+        let numer_overflow = ParseRatioError::overflow_in(OverflowSource::Numerator);
+        let denom_overflow = ParseRatioError::overflow_in(OverflowSource::Denominator);
+
+        let exp_val = match exp_str {
+            Some(exp_s) => {
+                let exp_clean_owned: String;
+                let exp_final = if exp_s.contains('_') {
+                    exp_clean_owned = exp_s.replace('_', "");
+                    &exp_clean_owned
+                } else {
+                    exp_s
+                };
+                exp_final
+                    .parse::<i32>()
+                    .map_err(|_| ParseRatioError::new(RatioErrorKind::ParseError))?
+            }
+            None => 0,
+        };
+
+        // The base the exponent scales by: 2 for a binary exponent, and the
+        // configured base otherwise. Only a base of ten combines with the scale
+        // of the decimal digits.
+        let exp_base = if binary_exp { 2 } else { self.exponent_base };
+        let base = match T::from_u32(exp_base) {
+            Some(base) => base,
+            // A base beyond `T` only matters if there is something to scale.
+            None if exp_val == 0 => T::one(),
+            None if exp_val > 0 => return Err(numer_overflow),
+            None => return Err(denom_overflow),
+        };
+        let decimal_base = exp_base == 10;
+
+        let mut numerator: T;
+        let mut denominator: T;
+        let (ten_exp, base_exp);
+
+        if let Some(d_str) = denom_str {
+            numerator = parse_val(num_str, negative).map_err(|_| numer_overflow)?;
+            denominator = parse_val(d_str, false).map_err(|_| denom_overflow)?;
+
+            // An exponent scales the whole fraction. Factors of its base on the
+            // other side of the fraction cancel first, so "1/10e1" is 1 without
+            // building 10/10, and "5/10e9" fits in a `Ratio<i32>`.
+            let mut exp = i64::from(exp_val);
+            if !numerator.is_zero() && !denominator.is_zero() {
+                while exp > 0 && denominator.is_multiple_of(&base) {
+                    denominator = denominator / base.clone();
+                    exp -= 1;
+                }
+                while exp < 0 && numerator.is_multiple_of(&base) {
+                    numerator = numerator / base.clone();
+                    exp += 1;
+                }
+            }
+            (ten_exp, base_exp) = (0, exp);
+        } else {
+            denominator = T::one();
+
+            // Strip trailing zeros to avoid unnecessary overflow and create more efficient rationals
+            // e.g., "1.0000000000" becomes "1.0" instead of creating denominator = 10^10
+            let dec_trimmed = if self.preserve_trailing_zeros {
+                decimal_str.unwrap_or("")
+            } else {
+                decimal_str.unwrap_or("").trim_end_matches(['0', '_'])
+            };
+            let dec_clean_owned: String;
+            let dec_final = if dec_trimmed.contains('_') {
+                dec_clean_owned = dec_trimmed.replace('_', "");
+                &dec_clean_owned
+            } else {
+                dec_trimmed
+            };
+
+            // Without decimals, trailing zeros of the integer part are moved into
+            // the exponent, where they cancel against a negative one. This way
+            // "100000000000e-11" never builds 10^11 just to reduce it to 1.
+            let (int_digits, int_zeros) = if dec_final.is_empty() && !self.preserve_trailing_zeros {
+                let kept = num_str.trim_end_matches(['0', '_']);
+                (kept, num_str[kept.len()..].matches('0').count())
+            } else {
+                (num_str, 0)
+            };
+            // Digits beyond the configured precision are dropped (or rejected)
+            // before they can overflow the numerator.
+            let mut dropped_digits = 0;
+            let significant: String = int_digits
+                .chars()
+                .chain(dec_final.chars())
+                .filter(|&c| c != '_')
+                .skip_while(|&c| c == '0')
+                .collect();
+            match self.max_significant_digits {
+                Some((limit, excess)) if significant.len() > limit => {
+                    if excess == ExcessDigits::Error {
+                        return Err(ParseRatioError::new(RatioErrorKind::TooManyDigits));
+                    }
+                    let (kept, dropped) = significant.split_at(limit);
+                    numerator = parse_val(kept, negative).map_err(|_| numer_overflow)?;
+                    if rounds_up(kept, dropped) {
+                        let step = if negative { -T::one() } else { T::one() };
+                        numerator = numerator.checked_add(&step).ok_or(numer_overflow)?;
+                    }
+                    dropped_digits = dropped.len();
+                }
+                _ => {
+                    numerator = parse_val(int_digits, negative).map_err(|_| numer_overflow)?;
+
+                    if !dec_final.is_empty() {
+                        let dec_val = parse_val(dec_final, negative).map_err(|_| numer_overflow)?;
+                        if !numerator.is_zero() {
+                            // Power of 10 equal to number of significant decimal digits
+                            let scale =
+                                checked_pow(&ten, dec_final.len() as u32).ok_or(numer_overflow)?;
+                            numerator = numerator.checked_mul(&scale).ok_or(numer_overflow)?;
+                        }
+                        numerator = numerator.checked_add(&dec_val).ok_or(numer_overflow)?;
+                    }
+                }
+            }
+
+            // The numerator now holds all significant digits, so the value is
+            // numerator * 10^(exponent - decimal digits). Applying the exponent
+            // and the decimal scale as one power avoids overflowing on inputs
+            // like "2.147483647e9" whose scales cancel out. An exponent in any
+            // other base cannot cancel with the decimal scale and is applied on
+            // its own.
+            let dec_exp = (int_zeros + dropped_digits) as i64 - dec_final.len() as i64;
+            (ten_exp, base_exp) = if decimal_base {
+                (dec_exp + i64::from(exp_val), 0)
+            } else {
+                (dec_exp, i64::from(exp_val))
+            };
+        }
+
+        if !numerator.is_zero() {
+            for (base, exp) in [(&ten, ten_exp), (&base, base_exp)] {
+                if exp == 0 {
+                    continue;
+                }
+                let overflow = if exp > 0 {
+                    numer_overflow
+                } else {
+                    denom_overflow
+                };
+                let scale = u32::try_from(exp.unsigned_abs())
+                    .ok()
+                    .and_then(|abs_exp| checked_pow(base, abs_exp))
+                    .ok_or(overflow)?;
+                if exp > 0 {
+                    numerator = numerator.checked_mul(&scale).ok_or(overflow)?;
+                } else {
+                    denominator = denominator.checked_mul(&scale).ok_or(overflow)?;
+                }
+            }
+        }
+
+        if denominator.is_zero() {
+            if self.zero_denominator == ZeroDenomPolicy::Saturate && !numerator.is_zero() {
+                let max = saturated_integer::<T>();
+                return Ok((if numerator.is_negative() { -max } else { max }, T::one()));
+            }
+            return Err(ParseRatioError::new(RatioErrorKind::ZeroDenominator));
+        }
+
+        Ok((numerator, denominator))
+    }
+}
+
+/// Diagnostics about a successful parse, as returned by
+/// [`RationalParser::parse_with_report`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseReport {
+    /// The value only fit in the target type after it was reduced in a wider
+    /// type, through [`RationalParser::reduce_before_overflow`] or
+    /// [`RationalParser::expected_decimal_digits`]. This suggests that the type
+    /// is marginal for the data.
+    pub reduced_to_fit: bool,
+}
+
+/// How a [`RationalParser`] handles decimals with more significant digits than
+/// allowed by [`RationalParser::max_significant_digits`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExcessDigits {
+    /// Fail with [`RatioErrorKind::TooManyDigits`].
+    Error,
+    /// Round half to even to the allowed number of digits.
+    Round,
+}
+
+/// How a [`RationalParser`] handles values that do not fit in the target type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowMode {
+    /// Fail with [`RatioErrorKind::Overflow`].
+    Error,
+    /// Replace the value with the nearest rational whose numerator and
+    /// denominator both fit in the target type.
+    ///
+    /// "Nearest" means the smallest absolute difference `|x - p/q|` to the exact
+    /// value `x`. It is found by a Stern–Brocot search over the continued
+    /// fraction of `x`, so this is a best rational approximation rather than a
+    /// truncation of digits. Values beyond the range of the type are saturated,
+    /// e.g. `"1e30"` parses to `i32::MAX` as a `Ratio<i32>`. The magnitude of
+    /// the numerator is limited to `T::MAX` for both signs.
+    ///
+    /// The exact value is computed in a `Ratio<i128>` first, so inputs that do
+    /// not even fit there still fail with [`RatioErrorKind::Overflow`].
+    NearestRepresentable,
+}
+
+/// How a [`RationalParser`] handles a fraction with a zero denominator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ZeroDenomPolicy {
+    /// Fail with [`RatioErrorKind::ZeroDenominator`].
+    Error,
+    /// Replace `n/0` by the largest integer of the target type, negated if `n`
+    /// is negative, as a stand-in for an infinite value.
+    ///
+    /// This is lossy: the result is an ordinary finite value, indistinguishable
+    /// from the same integer written out, and arithmetic on it will not behave
+    /// like infinity. The sentinel is `T::MAX` for the primitive integers, and
+    /// `-T::MAX` (not `T::MIN`) for negative numerators, so that both signs have
+    /// the same magnitude. For types wider than `i128`, it is `i128::MAX`.
+    ///
+    /// `"0/0"` has no sign and still fails with
+    /// [`RatioErrorKind::ZeroDenominator`], as does a zero divisor with
+    /// [`RationalParser::allow_chained_division`]. The sentinel replaces the
+    /// fraction itself, so a `%` suffix, for example, still divides it by 100.
+    Saturate,
+}
+
+/// Returns the largest integer that fits in both `T` and `i128`.
+fn saturated_integer<T: FlexInteger>() -> T {
+    nearest_representable(&Ratio::from_integer(i128::MAX))
+        .map(|r: Ratio<T>| r.to_integer())
+        .expect("every integer type holds 1")
+}
+
+/// Returns the nearest rational to `x` whose numerator and denominator fit in `T`.
+///
+/// The convergents of the continued fraction of `x` are generated until one no
+/// longer fits. The best approximation is then either the last convergent that
+/// fits, or the largest semiconvergent between it and the next one.
+fn nearest_representable<T: FlexInteger>(x: &Ratio<i128>) -> Option<Ratio<T>> {
+    let fits = |v: u128| T::from_u128(v).is_some();
+    let (mut n, mut d) = (x.numer().unsigned_abs(), x.denom().unsigned_abs());
+    // The two previous convergents, starting from the conventional 0/1 and 1/0.
+    let (mut h2, mut k2) = (0u128, 1u128);
+    let (mut h1, mut k1) = (1u128, 0u128);
+    let (numer, denom) = loop {
+        if d == 0 {
+            // The expansion has ended, and every convergent fitted.
+            break (h1, k1);
+        }
+        let (a, r) = (n / d, n % d);
+        let next = |m: u128| {
+            let h = m.checked_mul(h1)?.checked_add(h2)?;
+            let k = m.checked_mul(k1)?.checked_add(k2)?;
+            (fits(h) && fits(k)).then_some((h, k))
+        };
+        if let Some((h, k)) = next(a) {
+            (h2, k2, h1, k1) = (h1, k1, h, k);
+            (n, d) = (d, r);
+            continue;
+        }
+
+        // Find the largest `m < a` whose semiconvergent fits.
+        let (mut lo, mut hi) = (0, a);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if next(mid).is_some() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let m = lo;
+        let semi = next(m)?;
+        // The semiconvergent beats the previous convergent if `m > a/2`. At
+        // exactly `a/2`, it does so if the fractional part of the complete
+        // quotient `n/d` is below `k2/k1`.
+        let use_semi = k1 == 0
+            || (m > 0
+                && (2 * m > a || (2 * m == a && Ratio::new_raw(r, d) < Ratio::new_raw(k2, k1))));
+        break if use_semi { semi } else { (h1, k1) };
+    };
+
+    let numer = T::from_u128(numer)?;
+    let numer = if x.is_negative() { -numer } else { numer };
+    Some(Ratio::new(numer, T::from_u128(denom)?))
+}
+
+/// Emits a `debug` event for a failed parse, with the input cut to a bounded length.
+#[cfg(feature = "tracing")]
+fn trace_failure(input: &str, e: &ParseRatioError) {
+    const MAX_LEN: usize = 64;
+    let end = input
+        .char_indices()
+        .nth(MAX_LEN)
+        .map_or(input.len(), |(i, _)| i);
+    tracing::debug!(
+        input = &input[..end],
+        truncated = end < input.len(),
+        kind = ?e.kind(),
+        "failed to parse rational number"
+    );
+}
+
+/// Returns `true` if a digit string ending in `kept` must be rounded up when the
+/// digits `dropped` are cut off, rounding half to even.
+fn rounds_up(kept: &str, dropped: &str) -> bool {
+    let mut rest = dropped.bytes();
+    match rest.next() {
+        Some(b'6'..=b'9') => true,
+        Some(b'5') if rest.any(|b| b != b'0') => true,
+        Some(b'5') => kept.bytes().last().is_some_and(|b| (b - b'0') % 2 == 1),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the decimal expansion of `value` terminates.
+fn has_terminating_decimal<T: FlexInteger>(value: &Ratio<T>) -> bool {
+    let mut denom = value.denom().clone();
+    for factor in [2, 5] {
+        let factor = T::from_u8(factor).expect("small factors fit in any integer type");
+        while denom.is_multiple_of(&factor) {
+            denom = denom / factor.clone();
+        }
+    }
+    denom.is_one()
+}
+
+/// Splits `"a/b/c/..."` into the leading fraction `"a/b"` and the remaining
+/// divisors `"c/..."`, or returns `None` if there are fewer than two slashes.
+fn split_chained_division(input: &str) -> Option<(&str, &str)> {
+    let first = input.find('/')?;
+    let second = first + 1 + input[first + 1..].find('/')?;
+    Some((&input[..second], &input[second + 1..]))
+}
+
+/// Divides a parsed percentage by 100, reducing before scaling the denominator.
+fn percent_to_ratio<T: FlexInteger>(r: Ratio<T>) -> Result<Ratio<T>, ParseRatioError> {
+    let hundred = T::from_u8(100).ok_or(ParseRatioError::new(RatioErrorKind::Overflow))?;
+    let (numer, denom) = r.into_raw();
+    let gcd = numer.gcd(&hundred);
+    let denom = denom
+        .checked_mul(&(hundred / gcd.clone()))
+        .ok_or(ParseRatioError::new(RatioErrorKind::Overflow))?;
+    Ok(Ratio::new(numer / gcd, denom))
+}
+
+/// Parses a string into a rational number without importing [`RationalParse`].
+///
+/// This forwards to [`RationalParse::from_str_flex`] and accepts exactly the
+/// same formats; it only exists for callers who prefer a turbofish to a trait
+/// import.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+///
+/// let r = num_rational_parse::parse_flex::<i32>("3/4").unwrap();
+/// assert_eq!(r, Ratio::new(3, 4));
+/// ```
+pub fn parse_flex<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    Ratio::<T>::from_str_flex(s)
+}
+
+/// Parses a string into a `Ratio<i128>`, the widest fixed-size ratio type.
+///
+/// This is a convenience for callers who do not want to choose a backing
+/// integer type upfront. It accepts exactly the same formats as
+/// [`RationalParse::from_str_flex`]. Inputs whose value does not fit in `i128`
+/// still fail with [`RatioErrorKind::Overflow`]; for unbounded values, parse into
+/// `Ratio<BigInt>` from the `num-bigint` crate instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::parse_flex_widest;
+///
+/// let r = parse_flex_widest("12345678901234567890.5").unwrap();
+/// assert_eq!(r, Ratio::new(24691357802469135781, 2));
+/// ```
+pub fn parse_flex_widest(s: &str) -> Result<Ratio<i128>, ParseRatioError> {
+    Ratio::<i128>::from_str_flex(s)
+}
+
+/// Parses a rational number from UTF-8 bytes, such as a network or file
+/// payload, with [`RationalParse::from_str_flex`].
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if `bytes` is not valid UTF-8.
+/// Otherwise, the errors of [`RationalParse::from_str_flex`] are returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_bytes_flex;
+///
+/// assert_eq!(from_bytes_flex::<i32>(b"-3/4").unwrap(), Ratio::new(-3, 4));
+/// assert!(from_bytes_flex::<i32>(b"\xff").is_err());
+/// ```
+pub fn from_bytes_flex<T: FlexInteger>(bytes: &[u8]) -> Result<Ratio<T>, ParseRatioError> {
+    let text =
+        std::str::from_utf8(bytes).map_err(|_| ParseRatioError::new(RatioErrorKind::ParseError))?;
+    Ratio::<T>::from_str_flex(text)
+}
+
+/// Parses a string into the `f64` nearest to its exact rational value.
+///
+/// The input is parsed exactly with [`parse_flex_widest`] and then rounded once,
+/// to nearest with ties to even. Converting the numerator and denominator to
+/// `f64` separately and dividing would round three times, which can be off by
+/// one unit in the last place for fractions with large terms.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`parse_flex_widest`]; in particular, values that do not fit in a
+/// `Ratio<i128>` fail with [`RatioErrorKind::Overflow`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational_parse::parse_flex_f64;
+///
+/// assert_eq!(parse_flex_f64("1/3").unwrap(), 1.0 / 3.0);
+/// assert_eq!(parse_flex_f64("-2.5e-3").unwrap(), -0.0025);
+/// ```
+pub fn parse_flex_f64(s: &str) -> Result<f64, ParseRatioError> {
+    parse_flex_widest(s)?
+        .to_f64()
+        .ok_or(ParseRatioError::new(RatioErrorKind::Overflow))
+}
+
+/// What [`parse_flex_trimmed`] removed from its input.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Trimmed {
+    /// Whitespace surrounded the number.
+    pub whitespace: bool,
+    /// The numerator, denominator or exponent had redundant leading zeros, as
+    /// in `"007"`, `"1/02"` or `"1e05"`. A lone `0` such as in `"0.5"` does not
+    /// count, and zeros after the decimal point are significant.
+    pub leading_zeros: bool,
+}
+
+/// Parses a string like [`RationalParse::from_str_flex`], and reports whether
+/// the input had to be trimmed to do so.
+///
+/// This is meant for audit trails that need to record when an input was not in
+/// canonical form, without rejecting it.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{Trimmed, parse_flex_trimmed};
+///
+/// let (r, trimmed) = parse_flex_trimmed::<i32>(" 007/2").unwrap();
+/// assert_eq!(r, Ratio::new(7, 2));
+/// assert_eq!(trimmed, Trimmed { whitespace: true, leading_zeros: true });
+///
+/// let (_, trimmed) = parse_flex_trimmed::<i32>("0.05").unwrap();
+/// assert_eq!(trimmed, Trimmed::default());
+/// ```
+pub fn parse_flex_trimmed<T: FlexInteger>(s: &str) -> Result<(Ratio<T>, Trimmed), ParseRatioError> {
+    let value = Ratio::<T>::from_str_flex(s)?;
+    let trimmed = s.trim();
+    // The input parsed successfully, so it matches the grammar.
+    let cap = RATIONAL_FORMAT
+        .captures(trimmed)
+        .ok_or(ParseRatioError::new(RatioErrorKind::ParseError))?;
+    let padded = |name: &str| {
+        cap.name(name).is_some_and(|m| {
+            let digits = m.as_str().trim_start_matches(['+', '-']);
+            digits.len() > 1 && digits.starts_with('0')
+        })
+    };
+    let info = Trimmed {
+        whitespace: trimmed.len() != s.len(),
+        leading_zeros: padded("num") || padded("denom") || padded("exp"),
+    };
+    Ok((value, info))
+}
+
+/// The syntactic form of an input, as reported by [`classify_flex`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FormatClass {
+    /// An integer without a decimal point or exponent, like `"-42"`.
+    Integer,
+    /// A fraction, like `"3/4"`.
+    Fraction,
+    /// A number with a decimal point and no exponent, like `"3.14"` or `"5."`.
+    Decimal,
+    /// A number with an exponent, like `"1e5"` or `"-2.5E-3"`, whether or not
+    /// it has a decimal point.
+    Scientific,
+}
+
+/// Parses a string like [`RationalParse::from_str_flex`], and reports which
+/// syntactic form it was written in.
+///
+/// The form follows from the input alone, not from the value: `"4/2"` is a
+/// [`FormatClass::Fraction`] and `"2.0"` a [`FormatClass::Decimal`], although
+/// both are the integer `2`. This is meant for collecting statistics about the
+/// formats found in a data set.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{FormatClass, classify_flex};
+///
+/// assert_eq!(classify_flex::<i32>("3.14").unwrap().1, FormatClass::Decimal);
+/// assert_eq!(classify_flex::<i32>("1/2").unwrap(), (Ratio::new(1, 2), FormatClass::Fraction));
+/// assert_eq!(classify_flex::<i32>("1e5").unwrap().1, FormatClass::Scientific);
+/// ```
+pub fn classify_flex<T: FlexInteger>(s: &str) -> Result<(Ratio<T>, FormatClass), ParseRatioError> {
+    let value = Ratio::<T>::from_str_flex(s)?;
+    // The input parsed successfully, so it matches the grammar.
+    let cap = RATIONAL_FORMAT
+        .captures(s)
+        .ok_or(ParseRatioError::new(RatioErrorKind::ParseError))?;
+    let class = if cap.name("denom").is_some() {
+        FormatClass::Fraction
+    } else if cap.name("exp").is_some() {
+        FormatClass::Scientific
+    } else if cap.name("decimal").is_some() {
+        FormatClass::Decimal
+    } else {
+        FormatClass::Integer
+    };
+    Ok((value, class))
+}
+
+/// Checks whether a string is a valid rational number for the integer type `T`.
+///
+/// This accepts and rejects exactly the same inputs as
+/// [`RationalParse::from_str_flex`], but skips reducing the result, which makes
+/// it cheaper when the value itself is not needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational_parse::{RatioErrorKind, validate_flex};
+///
+/// assert!(validate_flex::<i32>("6/4").is_ok());
+/// assert_eq!(
+///     *validate_flex::<i8>("1/128").unwrap_err().kind(),
+///     RatioErrorKind::Overflow
+/// );
+/// ```
+pub fn validate_flex<T: FlexInteger>(s: &str) -> Result<(), ParseRatioError> {
+    RationalParser::new().parse_raw::<T>(s).map(|_| ())
+}
+
+/// How to handle a parsed value outside of an allowed range.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RangePolicy {
+    /// Replace the value with the nearest bound of the range.
+    Clamp,
+    /// Fail with [`RatioErrorKind::OutOfRange`].
+    Error,
+}
+
+/// Parses a string into a rational number in the closed interval `[0, 1]`.
+///
+/// This is intended for proportions and probabilities. Values outside the
+/// interval are clamped to `0` or `1`, or rejected, depending on `policy`.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`], and [`RatioErrorKind::OutOfRange`] if the
+/// value lies outside `[0, 1]` and `policy` is [`RangePolicy::Error`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RangePolicy, RatioErrorKind, from_str_flex_clamped01};
+///
+/// let r = from_str_flex_clamped01::<i32>("0.25", RangePolicy::Error).unwrap();
+/// assert_eq!(r, Ratio::new(1, 4));
+///
+/// let r = from_str_flex_clamped01::<i32>("3/2", RangePolicy::Clamp).unwrap();
+/// assert_eq!(r, Ratio::new(1, 1));
+///
+/// let err = from_str_flex_clamped01::<i32>("-1e-3", RangePolicy::Error).unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::OutOfRange);
+/// ```
+pub fn from_str_flex_clamped01<T: FlexInteger>(
+    s: &str,
+    policy: RangePolicy,
+) -> Result<Ratio<T>, ParseRatioError> {
+    let value = Ratio::<T>::from_str_flex(s)?;
+    let bound = if value.is_negative() {
+        Ratio::zero()
+    } else if value > Ratio::one() {
+        Ratio::one()
+    } else {
+        return Ok(value);
+    };
+    match policy {
+        RangePolicy::Clamp => Ok(bound),
+        RangePolicy::Error => Err(ParseRatioError::new(RatioErrorKind::OutOfRange)),
+    }
+}
+
+/// Parses a string into a rational number that must be a whole number.
+///
+/// The check is made on the reduced value with [`Ratio::is_integer`], so `"3"`,
+/// `"6/2"`, `"2.50e1"` and `"-0"` pass, while `"3/2"` and `"0.5"` do not.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`], and [`RatioErrorKind::NonInteger`] if the
+/// value has a fractional part.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_integer};
+///
+/// assert_eq!(from_str_flex_integer::<i32>("6/2").unwrap(), Ratio::from_integer(3));
+///
+/// let err = from_str_flex_integer::<i32>("3/2").unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::NonInteger);
+/// ```
+pub fn from_str_flex_integer<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let value = Ratio::<T>::from_str_flex(s)?;
+    if !value.is_integer() {
+        return Err(ParseRatioError::new(RatioErrorKind::NonInteger));
+    }
+    Ok(value)
+}
+
+/// Parses a string into an integer, accepting every format of
+/// [`RationalParse::from_str_flex`].
+///
+/// This is [`from_str_flex_integer`] for callers who want the integer itself:
+/// `"6/2"` is `3`, `"3e2"` is `300` and `"-2.50e1"` is `-25`.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`], including [`RatioErrorKind::Overflow`] if
+/// the value does not fit in `T`, and [`RatioErrorKind::NonInteger`] if the
+/// value has a fractional part.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_as_int};
+///
+/// assert_eq!(from_str_flex_as_int::<i32>("6/2").unwrap(), 3);
+/// assert_eq!(from_str_flex_as_int::<i64>("3e2").unwrap(), 300);
+///
+/// let err = from_str_flex_as_int::<i32>("3/2").unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::NonInteger);
+/// ```
+pub fn from_str_flex_as_int<T: FlexInteger>(s: &str) -> Result<T, ParseRatioError> {
+    // The value is reduced, so the denominator of an integer is 1.
+    from_str_flex_integer::<T>(s).map(|r| r.into_raw().0)
+}
+
+/// Parses a string and compares its value to `reference`.
+///
+/// This is a shorthand for filters like "keep rows above 1/2". The comparison
+/// is exact, so `"0.5"` is [`Ordering::Equal`](std::cmp::Ordering::Equal) to
+/// `1/2`.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::compare_flex;
+/// use std::cmp::Ordering;
+///
+/// let half = Ratio::new(1, 2);
+/// assert_eq!(compare_flex::<i32>("0.75", &half).unwrap(), Ordering::Greater);
+/// assert_eq!(compare_flex::<i32>("2/4", &half).unwrap(), Ordering::Equal);
+/// assert!(compare_flex::<i32>("x", &half).is_err());
+/// ```
+pub fn compare_flex<T: FlexInteger>(
+    s: &str,
+    reference: &Ratio<T>,
+) -> Result<std::cmp::Ordering, ParseRatioError> {
+    Ok(Ratio::<T>::from_str_flex(s)?.cmp(reference))
+}
+
+/// Parses a string into a rational number and returns its reciprocal.
+///
+/// This suits values given as their inverse, like a rate written as a period:
+/// `"0.25"` is `4`, `"3/4"` is `4/3` and `"-2"` is `-1/2`. The sign stays on
+/// the numerator.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`], [`RatioErrorKind::ZeroDenominator`] if the
+/// value is zero and has no reciprocal, and [`RatioErrorKind::Overflow`] if the
+/// reciprocal does not fit in `T`, as for `"-128"` with `i8`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_reciprocal};
+///
+/// assert_eq!(from_str_flex_reciprocal::<i32>("0.25").unwrap(), Ratio::from_integer(4));
+/// assert_eq!(from_str_flex_reciprocal::<i32>("-3/4").unwrap(), Ratio::new(-4, 3));
+///
+/// let err = from_str_flex_reciprocal::<i32>("0").unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::ZeroDenominator);
+/// ```
+pub fn from_str_flex_reciprocal<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let (numer, denom) = Ratio::<T>::from_str_flex(s)?.into_raw();
+    if numer.is_zero() {
+        return Err(ParseRatioError::new(RatioErrorKind::ZeroDenominator));
+    }
+    if !numer.is_negative() {
+        return Ok(Ratio::new_raw(denom, numer));
+    }
+    // `Ratio::recip` would negate the numerator, which overflows for the most
+    // negative value of `T`.
+    let overflow = ParseRatioError::overflow_in(OverflowSource::Denominator);
+    let denom_abs = T::zero().checked_sub(&numer).ok_or(overflow)?;
+    Ok(Ratio::new_raw(-denom, denom_abs))
+}
+
+/// A parsed number, tagged by whether it is a whole number, as returned by
+/// [`parse_number`].
+#[derive(Copy, Clone, Debug)]
+pub enum ParsedNumber<T> {
+    /// The value is a whole number.
+    Integer(T),
+    /// The value has a fractional part, so its denominator is greater than 1.
+    Rational(Ratio<T>),
+}
+
+// `ParsedNumber::Rational` only compares with `T: Clone + Integer`, a bound a
+// derive would not add. Different variants are never equal: `parse_number`
+// does not produce a whole `Rational`, and one built by hand compares unequal
+// to the `Integer` of the same value.
+impl<T: Clone + Integer> PartialEq for ParsedNumber<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ParsedNumber::Integer(a), ParsedNumber::Integer(b)) => a == b,
+            (ParsedNumber::Rational(a), ParsedNumber::Rational(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Clone + Integer> Eq for ParsedNumber<T> {}
+
+impl<T: Clone + Integer> ParsedNumber<T> {
+    /// Returns the value as a ratio, with a denominator of 1 for integers.
+    pub fn into_ratio(self) -> Ratio<T> {
+        match self {
+            ParsedNumber::Integer(n) => Ratio::from_integer(n),
+            ParsedNumber::Rational(r) => r,
+        }
+    }
+}
+
+/// Parses a string into a rational number, and tags it as an integer if it is
+/// a whole number.
+///
+/// This lets callers take an integer path without checking the denominator:
+/// `"6/2"` and `"3e2"` are [`ParsedNumber::Integer`], while `"3/2"` is
+/// [`ParsedNumber::Rational`]. The check is made on the reduced value, like in
+/// [`from_str_flex_integer`].
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{ParsedNumber, parse_number};
+///
+/// assert_eq!(parse_number::<i32>("6/2").unwrap(), ParsedNumber::Integer(3));
+/// assert_eq!(
+///     parse_number::<i32>("3/2").unwrap(),
+///     ParsedNumber::Rational(Ratio::new(3, 2))
+/// );
+/// ```
+pub fn parse_number<T: FlexInteger>(s: &str) -> Result<ParsedNumber<T>, ParseRatioError> {
+    let value = Ratio::<T>::from_str_flex(s)?;
+    Ok(if value.is_integer() {
+        ParsedNumber::Integer(value.into_raw().0)
+    } else {
+        ParsedNumber::Rational(value)
+    })
+}
+
+/// Parses a string into a rational number whose denominator is one of `allowed`.
+///
+/// This suits fields with a fixed set of denominators, like note durations in
+/// halves, quarters and eighths. The check is made on the reduced value, so
+/// with `[2, 4, 8]`, `"6/8"` passes as `3/4`, and `"0.375"` as `3/8`. Whole
+/// numbers have a denominator of 1, which must be listed to accept them.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`], and
+/// [`RatioErrorKind::DenominatorNotAllowed`] if the reduced denominator is not
+/// in `allowed`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_with_allowed_denoms};
+///
+/// let notes = [2, 4, 8, 16];
+/// assert_eq!(from_str_flex_with_allowed_denoms("3/4", &notes).unwrap(), Ratio::new(3, 4));
+///
+/// let err = from_str_flex_with_allowed_denoms("1/3", &notes).unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::DenominatorNotAllowed);
+/// ```
+pub fn from_str_flex_with_allowed_denoms<T: FlexInteger>(
+    s: &str,
+    allowed: &[T],
+) -> Result<Ratio<T>, ParseRatioError> {
+    let value = Ratio::<T>::from_str_flex(s)?;
+    if !allowed.contains(value.denom()) {
+        return Err(ParseRatioError::new(RatioErrorKind::DenominatorNotAllowed));
+    }
+    Ok(value)
+}
+
+/// Parses a string into a rational number, reading a bare integer as a
+/// numerator over `default_denom`.
+///
+/// This suits formats where the denominator is usually implied, like pixel
+/// positions in sixteenths: with a default of 16, `"3"` is `3/16`, while an
+/// explicit fraction like `"5/8"` keeps its own denominator. Decimals and
+/// numbers with an exponent are complete on their own and ignore the default,
+/// so `"3.0"` and `"3e0"` are both `3`. See [`classify_flex`] for how the form
+/// of the input is determined.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ZeroDenominator`] if `default_denom` is zero,
+/// whatever the input. Otherwise returns [`ParseRatioError`] under the same
+/// conditions as [`RationalParse::from_str_flex`], and
+/// [`RatioErrorKind::Overflow`] if the integer divided by the default does not
+/// fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_str_flex_default_denom;
+///
+/// assert_eq!(from_str_flex_default_denom("3", 16).unwrap(), Ratio::new(3, 16));
+/// assert_eq!(from_str_flex_default_denom("5/8", 16).unwrap(), Ratio::new(5, 8));
+/// assert_eq!(from_str_flex_default_denom("0.5", 16).unwrap(), Ratio::new(1, 2));
+/// ```
+pub fn from_str_flex_default_denom<T: FlexInteger>(
+    s: &str,
+    default_denom: T,
+) -> Result<Ratio<T>, ParseRatioError> {
+    if default_denom.is_zero() {
+        return Err(ParseRatioError::new(RatioErrorKind::ZeroDenominator));
+    }
+    match classify_flex::<T>(s)? {
+        (value, FormatClass::Integer) => value
+            .checked_div(&Ratio::from_integer(default_denom))
+            .ok_or(ParseRatioError::new(RatioErrorKind::Overflow)),
+        (value, _) => Ok(value),
+    }
+}
+
+/// Parses a string into a rational number over an unsigned integer type, such as
+/// `Ratio<u32>`.
+///
+/// The parsers of this crate work on signed integers, so the input is parsed
+/// into a `Ratio<i128>` with [`RationalParse::from_str_flex`] and then
+/// converted. Negative values are rejected with a dedicated error kind, while
+/// negative zero, as in `"-0"` or `"-0/5"`, is simply zero.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`] for `i128`,
+/// [`RatioErrorKind::UnsignedNegative`] if the value is negative, and
+/// [`RatioErrorKind::Overflow`] if the reduced value does not fit in `U`. Going
+/// through `i128` means that a `u128` numerator or denominator above
+/// `i128::MAX` also overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_unsigned};
+///
+/// assert_eq!(from_str_flex_unsigned::<u32>("1.25").unwrap(), Ratio::new(5, 4));
+///
+/// let err = from_str_flex_unsigned::<u32>("-3/4").unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::UnsignedNegative);
+/// ```
+pub fn from_str_flex_unsigned<U>(s: &str) -> Result<Ratio<U>, ParseRatioError>
+where
+    U: Clone + Integer + num_traits::Unsigned + num_traits::NumCast,
+{
+    let value = Ratio::<i128>::from_str_flex(s)?;
+    if value.is_negative() {
+        return Err(ParseRatioError::new(RatioErrorKind::UnsignedNegative));
+    }
+    widen(value).ok_or(ParseRatioError::new(RatioErrorKind::Overflow))
+}
+
+/// Parses a string into a rational number along with its canonical string form.
+///
+/// The string is the reduced value as formatted by [`Ratio`]'s `Display`:
+/// `"numer/denom"` with the sign on the numerator, or just the numerator for
+/// whole numbers. It is therefore stable across equivalent inputs, which makes
+/// it suitable for echoing normalized input back to a user.
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_str_flex_canonical;
+///
+/// let (value, text) = from_str_flex_canonical::<i32>("-1_000/2_000").unwrap();
+/// assert_eq!(value, Ratio::new(-1, 2));
+/// assert_eq!(text, "-1/2");
+///
+/// assert_eq!(from_str_flex_canonical::<i32>("6/2").unwrap().1, "3");
+/// ```
+pub fn from_str_flex_canonical<T: FlexInteger + std::fmt::Display>(
+    s: &str,
+) -> Result<(Ratio<T>, String), ParseRatioError> {
+    let value = Ratio::<T>::from_str_flex(s)?;
+    let text = value.to_string();
+    Ok((value, text))
+}
+
+/// Parses a string into a rational number along with the common factor that
+/// was cancelled to reduce it.
+///
+/// For a fraction, the factor is the greatest common divisor of the numerator
+/// and denominator as written: `"6/4"` gives `(3/2, 2)`, and `"3/4"` gives
+/// `(3/4, 1)`. A decimal is first read as its significant digits over a power
+/// of ten, after trailing zeros have been stripped, so `"0.5"` and `"1.50"`
+/// (read as `5/10` and `15/10`) both give a factor of `5`. The factor is always
+/// positive, and the value is the same as from [`RationalParse::from_str_flex`].
+///
+/// # Errors
+///
+/// Returns [`ParseRatioError`] under the same conditions as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_str_flex_with_gcd;
+///
+/// assert_eq!(from_str_flex_with_gcd::<i32>("6/4").unwrap(), (Ratio::new(3, 2), 2));
+/// assert_eq!(from_str_flex_with_gcd::<i32>("-3/4").unwrap(), (Ratio::new(-3, 4), 1));
+/// ```
+pub fn from_str_flex_with_gcd<T: FlexInteger>(s: &str) -> Result<(Ratio<T>, T), ParseRatioError> {
+    let (numer, denom) = RationalParser::new().parse_raw::<T>(s)?;
+    let gcd = numer.gcd(&denom);
+    Ok((Ratio::new(numer, denom), gcd))
+}
+
+/// Parses a string into a rational number, handing inputs the grammar does not
+/// recognize to `fallback`.
+///
+/// The fallback receives the original string and only runs if parsing fails
+/// with [`RatioErrorKind::ParseError`], [`RatioErrorKind::DanglingExponent`] or
+/// [`RatioErrorKind::NonAsciiDigit`]. Other errors, like an overflow or a zero
+/// denominator, mean that the input is a number of the standard notation that
+/// cannot be used, and are returned as is. This allows layering a custom
+/// notation on top of the standard one.
+///
+/// # Errors
+///
+/// Returns the error of [`RationalParse::from_str_flex`] if it is not a syntax
+/// error, and the result of `fallback` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_or_else};
+///
+/// let half = |s: &str| match s {
+///     "half" => Ok(Ratio::new(1, 2)),
+///     _ => Err(RatioErrorKind::ParseError.into()),
+/// };
+/// assert_eq!(from_str_flex_or_else::<i32>("half", half).unwrap(), Ratio::new(1, 2));
+/// assert_eq!(from_str_flex_or_else::<i32>("3/4", half).unwrap(), Ratio::new(3, 4));
+/// ```
+pub fn from_str_flex_or_else<T: FlexInteger>(
+    s: &str,
+    fallback: impl FnOnce(&str) -> Result<Ratio<T>, ParseRatioError>,
+) -> Result<Ratio<T>, ParseRatioError> {
+    match Ratio::<T>::from_str_flex(s) {
+        Err(e)
+            if matches!(
+                e.kind,
+                RatioErrorKind::ParseError
+                    | RatioErrorKind::DanglingExponent
+                    | RatioErrorKind::NonAsciiDigit { .. }
+            ) =>
+        {
+            fallback(s)
+        }
+        result => result,
+    }
+}
+
+/// Parses a rational literal with an optional integer type suffix, like `"3/4_i32"`.
+///
+/// The suffixes `_i8`, `_i16`, `_i32`, `_i64` and `_i128` are recognized, and
+/// must directly follow the number. The suffix is stripped, and the reduced
+/// value must then fit in a `Ratio` of the named type, in addition to `T`, so
+/// `"200/4_i8"` is accepted as `50`. This mirrors
+/// how Rust checks typed literals and lets tools validate literals against
+/// their declared type. Without a suffix, this is the same as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] for an unknown suffix (e.g. `_u8` or
+/// `_f64`), [`RatioErrorKind::Overflow`] if the value does not fit in the
+/// suffix type, and otherwise the same errors as
+/// [`RationalParse::from_str_flex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_typed};
+///
+/// assert_eq!(from_str_flex_typed::<i64>("3/4_i32").unwrap(), Ratio::new(3, 4));
+///
+/// let err = from_str_flex_typed::<i64>("300_i8").unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+/// ```
+pub fn from_str_flex_typed<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let trimmed = s.trim_end();
+    // A suffix starts with a letter, unlike a group of digits after a `_`.
+    let (number, suffix) = match trimmed.rsplit_once('_') {
+        Some((number, suffix)) if suffix.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+            (number, suffix)
+        }
+        _ => return Ratio::<T>::from_str_flex(s),
+    };
+    if number.ends_with(char::is_whitespace) {
+        return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+    }
+    // The reduced value has to fit the suffix type, not the parts as written,
+    // so it is parsed at the widest type first.
+    fn check_fits<U: num_traits::NumCast>(value: &Ratio<i128>) -> Result<(), ParseRatioError> {
+        if U::from(*value.numer()).is_none() {
+            return Err(ParseRatioError::overflow_in(OverflowSource::Numerator));
+        }
+        if U::from(*value.denom()).is_none() {
+            return Err(ParseRatioError::overflow_in(OverflowSource::Denominator));
+        }
+        Ok(())
+    }
+    let check: fn(&Ratio<i128>) -> Result<(), ParseRatioError> = match suffix {
+        "i8" => check_fits::<i8>,
+        "i16" => check_fits::<i16>,
+        "i32" => check_fits::<i32>,
+        "i64" => check_fits::<i64>,
+        "i128" => check_fits::<i128>,
+        _ => return Err(ParseRatioError::new(RatioErrorKind::ParseError)),
+    };
+    check(&Ratio::<i128>::from_str_flex(number)?)?;
+    Ratio::<T>::from_str_flex(number)
+}
+
+/// Parses a string like [`RationalParse::from_str_flex`], but reports every
+/// problem found instead of only the first one.
+///
+/// This is meant for form validation, where showing all problems at once is
+/// friendlier. The parts of a well-formed input are checked independently:
+/// for a fraction, both the numerator and the denominator; for a number with
+/// an exponent that is missing or does not fit in an `i32`, the mantissa and
+/// the exponent. So `"99999999999/0"` reports both an overflow of the
+/// numerator and a zero denominator, and `"99999999999e99999999999"` both an
+/// overflow of the mantissa and an invalid exponent. A valid exponent is not
+/// checked apart from its mantissa, since trailing zeros of the mantissa can
+/// cancel against it. Input that does not match the grammar cannot be split
+/// into parts, and yields a single error.
+///
+/// On success, the result is identical to [`RationalParse::from_str_flex`].
+///
+/// # Errors
+///
+/// Returns the errors in the order of the parts they concern. The list is never
+/// empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational_parse::{RatioErrorKind, from_str_flex_all_errors};
+///
+/// let errors = from_str_flex_all_errors::<i32>("99999999999/0").unwrap_err();
+/// let kinds: Vec<_> = errors.iter().map(|e| *e.kind()).collect();
+/// assert_eq!(kinds, [RatioErrorKind::Overflow, RatioErrorKind::ZeroDenominator]);
+/// ```
+pub fn from_str_flex_all_errors<T: FlexInteger>(s: &str) -> Result<Ratio<T>, Vec<ParseRatioError>> {
+    let first = match Ratio::<T>::from_str_flex(s) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+    let cap = match RATIONAL_FORMAT.captures(s) {
+        Some(cap) if has_valid_alphabet(s, &['_'], false) => cap,
+        _ => return Err(vec![first]),
+    };
+
+    let mut errors = Vec::new();
+    if let Some(denom) = cap.name("denom") {
+        let sign = cap.name("sign").map_or("", |m| m.as_str());
+        let numer = cap.name("num").map_or("", |m| m.as_str());
+        if !numer.is_empty() {
+            errors.extend(validate_flex::<T>(&format!("{}{}", sign, numer)).err());
+            errors.extend(validate_flex::<T>(&format!("1/{}", denom.as_str())).err());
+        }
+    } else if let (Some(marker), Some(exp)) = (cap.name("marker"), cap.name("exp")) {
+        let exp_error = if exp.as_str().trim_start_matches(['+', '-']).is_empty() {
+            Some(RatioErrorKind::DanglingExponent)
+        } else if exp.as_str().replace('_', "").parse::<i32>().is_err() {
+            Some(RatioErrorKind::ParseError)
+        } else {
+            None
+        };
+        if let Some(kind) = exp_error {
+            errors.extend(validate_flex::<T>(&s[..marker.start()]).err());
+            errors.push(ParseRatioError::new(kind));
+        }
+    }
+    if errors.is_empty() {
+        errors.push(first);
+    }
+    Err(errors)
+}
+
+/// Parses a percentage and applies it to `base`.
+///
+/// The input must end with a `%` sign, e.g. `"50%"` or `"12.5 %"`; the result is
+/// `base * percentage / 100`. This models inputs such as "50% of the budget".
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if the input is not a number followed by
+/// `%`, and [`RatioErrorKind::Overflow`] if the percentage or the product does not
+/// fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_percent_of;
+///
+/// let budget = Ratio::new(300, 1);
+/// assert_eq!(from_percent_of("50%", budget).unwrap(), Ratio::new(150, 1));
+/// assert_eq!(from_percent_of("12.5%", budget).unwrap(), Ratio::new(75, 2));
+/// ```
+pub fn from_percent_of<T: FlexInteger>(
+    s: &str,
+    base: Ratio<T>,
+) -> Result<Ratio<T>, ParseRatioError> {
+    parse_percent::<T>(s)
+        .and_then(|percent| {
+            percent
+                .checked_mul(&base)
+                .ok_or(ParseRatioError::new(RatioErrorKind::Overflow))
+        })
+        .map_err(|e| RationalParser::new().report_failure(s, e))
+}
+
+/// Parses a signed percentage change into the multiplier it stands for.
+///
+/// The input must end with a `%` sign; the result is `1 + percentage / 100`,
+/// so `"+5%"` is `21/20` and `"-10%"` is `9/10`. The sign is optional, and a
+/// change of `"-100%"` gives zero. Compare [`RationalParser::allow_percent`],
+/// which reads `"5%"` as `1/20`.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if the input is not a number followed by
+/// `%`, and [`RatioErrorKind::Overflow`] if the percentage or the multiplier does
+/// not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_percent_change;
+///
+/// assert_eq!(from_percent_change::<i32>("+5%").unwrap(), Ratio::new(21, 20));
+/// assert_eq!(from_percent_change::<i32>("-10%").unwrap(), Ratio::new(9, 10));
+/// assert_eq!(from_percent_change::<i32>("2.5 %").unwrap(), Ratio::new(41, 40));
+/// ```
+pub fn from_percent_change<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    parse_percent::<T>(s)
+        .and_then(|change| {
+            change
+                .checked_add(&Ratio::one())
+                .ok_or(ParseRatioError::new(RatioErrorKind::Overflow))
+        })
+        .map_err(|e| RationalParser::new().report_failure(s, e))
+}
+
+/// Parses a number followed by a `%` sign into the ratio it stands for, so
+/// `"5%"` is `1/20`.
+fn parse_percent<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let parser = RationalParser::new();
+    parser.check_ascii_digits(s)?;
+    let rest = s
+        .trim_end()
+        .strip_suffix('%')
+        .ok_or(ParseRatioError::new(RatioErrorKind::ParseError))?;
+    percent_to_ratio(parser.parse_core::<T>(rest)?)
+}
+
+/// Parses a fixed-point integer with an implied number of decimal places.
+///
+/// The input is an integer, optionally signed and grouped with `_`, that is
+/// divided by `10^scale`: `"12345"` with a scale of 3 is `12.345 = 2469/200`.
+/// Trailing zeros cancel against the scale first, so `"1000"` with a scale of
+/// 12 fits in a `Ratio<i32>` as `1/10^9`.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if the input is not an integer, which
+/// includes a decimal point, a fraction or an exponent, since the scale is
+/// implied. Returns [`RatioErrorKind::Overflow`] if the value does not fit in
+/// `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_fixed_point};
+///
+/// assert_eq!(from_fixed_point::<i32>("12345", 3).unwrap(), Ratio::new(2469, 200));
+/// assert_eq!(from_fixed_point::<i32>("-250", 2).unwrap(), Ratio::new(-5, 2));
+///
+/// let err = from_fixed_point::<i32>("12.345", 3).unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::ParseError);
+/// ```
+pub fn from_fixed_point<T: FlexInteger>(s: &str, scale: u32) -> Result<Ratio<T>, ParseRatioError> {
+    let parse = || {
+        let parser = RationalParser::new();
+        // Positions must refer to `s`, not to the rewritten input below.
+        parser.check_ascii_digits(s)?;
+        let trimmed = s.trim();
+        if trimmed.contains(['.', '/', 'e', 'E']) {
+            return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+        }
+        // A scale beyond the range of the exponent cannot be represented anyway.
+        let exp = i32::try_from(scale)
+            .map_err(|_| ParseRatioError::overflow_in(OverflowSource::Denominator))?;
+        parser.parse_core(&format!("{trimmed}e-{exp}"))
+    };
+    parse().map_err(|e| RationalParser::new().report_failure(s, e))
+}
+
+/// Parses a numerator and a denominator written as two whitespace-separated
+/// integers, as in the legacy format `"3 4"` for `3/4`.
+///
+/// Exactly two tokens are required, each an integer with an optional sign and
+/// optional `_` grouping. The signs combine as in a fraction, so `"3 -4"` is
+/// `-3/4`. This conflicts with the whitespace allowed around the `/` of a
+/// fraction, so no other parser accepts this format.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if the input does not consist of two
+/// integer tokens, [`RatioErrorKind::ZeroDenominator`] if the second one is
+/// zero, and [`RatioErrorKind::Overflow`] if either does not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_space_pair};
+///
+/// assert_eq!(from_space_pair::<i32>("3 4").unwrap(), Ratio::new(3, 4));
+/// assert_eq!(from_space_pair::<i32>(" -6\t8 ").unwrap(), Ratio::new(-3, 4));
+///
+/// let err = from_space_pair::<i32>("3/4").unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::ParseError);
+/// ```
+pub fn from_space_pair<T: FlexInteger>(s: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let parse = || {
+        let parser = RationalParser::new().allow_signed_denominator(true);
+        parser.check_ascii_digits(s)?;
+        let mut tokens = s.split_whitespace();
+        let (numer, denom) = match (tokens.next(), tokens.next(), tokens.next()) {
+            (Some(numer), Some(denom), None) => (numer, denom),
+            _ => return Err(ParseRatioError::new(RatioErrorKind::ParseError)),
+        };
+        let is_integer = |t: &str| t.chars().all(|c| c.is_ascii_digit() || "+-_".contains(c));
+        if !is_integer(numer) || !is_integer(denom) {
+            return Err(ParseRatioError::new(RatioErrorKind::ParseError));
+        }
+        // The fraction grammar checks the placement of signs and separators.
+        parser.parse_core(&format!("{numer}/{denom}"))
+    };
+    parse().map_err(|e| RationalParser::new().report_failure(s, e))
 }
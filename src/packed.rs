@@ -0,0 +1,131 @@
+//! A compact binary encoding of rationals, enabled by the `packed` feature.
+//!
+//! A ratio is stored as its numerator in signed LEB128, followed by its
+//! denominator in unsigned LEB128. Both are variable-length integers holding
+//! seven bits per byte, least significant group first, with the high bit set on
+//! every byte but the last.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind};
+use num_rational::Ratio;
+
+/// Multiplies by 2^7 one bit at a time, as 128 itself does not fit in an `i8`.
+fn shl7<T: FlexInteger>(value: T, two: &T) -> Option<T> {
+    (0..7).try_fold(value, |v, _| v.checked_mul(two))
+}
+
+/// Reads one LEB128 integer, returning it and the number of bytes consumed.
+fn read_leb128<T: FlexInteger>(bytes: &[u8], signed: bool) -> Result<(T, usize), ParseRatioError> {
+    let len = bytes
+        .iter()
+        .position(|b| b & 0x80 == 0)
+        .ok_or(ParseRatioError::new(RatioErrorKind::ParseError))?
+        + 1;
+    let overflow = || ParseRatioError::new(RatioErrorKind::Overflow);
+    let two = T::from_u8(2).ok_or_else(overflow)?;
+
+    // Accumulate from the most significant group, so that a negative value
+    // never passes through an intermediate that is out of range.
+    let (last, rest) = bytes[..len].split_last().expect("len is at least 1");
+    let mut value = if signed && last & 0x40 != 0 {
+        T::from_i16(i16::from(*last) - 0x80)
+    } else {
+        T::from_u8(*last)
+    }
+    .ok_or_else(overflow)?;
+    for byte in rest.iter().rev() {
+        let low = T::from_u8(byte & 0x7f).ok_or_else(overflow)?;
+        value = shl7(value, &two)
+            .and_then(|v| v.checked_add(&low))
+            .ok_or_else(overflow)?;
+    }
+    Ok((value, len))
+}
+
+/// Appends `value` in LEB128 to `out`. Unsigned encoding requires a non-negative value.
+fn write_leb128<T: FlexInteger>(mut value: T, signed: bool, out: &mut Vec<u8>) {
+    let two = T::from_u8(2).expect("2 fits in any integer type");
+    loop {
+        // Split off the low seven bits with floor division, which also works
+        // for negative values in two's complement terms.
+        let mut group = 0u8;
+        for bit in 0..7 {
+            let (quot, rem) = value.div_mod_floor(&two);
+            if rem.is_one() {
+                group |= 1 << bit;
+            }
+            value = quot;
+        }
+        let done = if signed {
+            let sign_bit = group & 0x40 != 0;
+            (value.is_zero() && !sign_bit) || (value == -T::one() && sign_bit)
+        } else {
+            value.is_zero()
+        };
+        if done {
+            out.push(group);
+            return;
+        }
+        out.push(group | 0x80);
+    }
+}
+
+/// Reads a rational from the start of `bytes` in the packed binary format.
+///
+/// Returns the reduced ratio and the number of bytes consumed, so that several
+/// values can be read from one buffer. Non-minimal encodings (with redundant
+/// continuation bytes) are accepted.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if the input ends in the middle of a
+/// value, [`RatioErrorKind::ZeroDenominator`] for a zero denominator, and
+/// [`RatioErrorKind::Overflow`] if a value does not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_bytes_packed;
+///
+/// let (r, len) = from_bytes_packed::<i32>(&[0x7d, 0x04, 0xff]).unwrap();
+/// assert_eq!(r, Ratio::new(-3, 4));
+/// assert_eq!(len, 2);
+/// ```
+pub fn from_bytes_packed<T: FlexInteger>(
+    bytes: &[u8],
+) -> Result<(Ratio<T>, usize), ParseRatioError> {
+    let (numer, numer_len) = read_leb128::<T>(bytes, true)?;
+    let (denom, denom_len) = read_leb128::<T>(&bytes[numer_len..], false)?;
+    if denom.is_zero() {
+        return Err(ParseRatioError::new(RatioErrorKind::ZeroDenominator));
+    }
+    Ok((Ratio::new(numer, denom), numer_len + denom_len))
+}
+
+/// Writes a rational in the packed binary format read by [`from_bytes_packed`].
+///
+/// The numerator and denominator are written as stored, so an unreduced ratio
+/// created with [`Ratio::new_raw`] keeps its terms.
+///
+/// # Panics
+///
+/// Panics if the denominator is negative.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{from_bytes_packed, to_bytes_packed};
+///
+/// let r = Ratio::new(-300i64, 7);
+/// let bytes = to_bytes_packed(&r);
+/// assert_eq!(bytes, [0xd4, 0x7d, 0x07]);
+/// assert_eq!(from_bytes_packed(&bytes).unwrap(), (r, 3));
+/// ```
+pub fn to_bytes_packed<T: FlexInteger>(r: &Ratio<T>) -> Vec<u8> {
+    assert!(!r.denom().is_negative(), "negative denominator");
+    let mut out = Vec::new();
+    write_leb128(r.numer().clone(), true, &mut out);
+    write_leb128(r.denom().clone(), false, &mut out);
+    out
+}
@@ -0,0 +1,225 @@
+//! Incremental validation of rational number strings.
+//!
+//! [`PartialParser`] implements the grammar of [`RationalParse::from_str_flex`]
+//! one character at a time, which makes it suitable for live input validation
+//! (e.g. an input mask in a TUI or GUI).
+//!
+//! [`RationalParse::from_str_flex`]: crate::RationalParse::from_str_flex
+
+use crate::{FlexInteger, ParseRatioError, RationalParse};
+use num_rational::Ratio;
+
+/// The outcome of feeding a single character to a [`PartialParser`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FeedResult {
+    /// The character was accepted, but the input is not yet a complete number
+    /// (e.g. `"-"`, `"3/"` or `"1e"`).
+    Accept,
+    /// The character cannot continue the input. The parser state is unchanged.
+    Reject,
+    /// The character was accepted and the input is now a complete number.
+    ///
+    /// More characters may still follow, e.g. `"3"` can be continued to `"3.5"`.
+    Complete,
+}
+
+/// The grammar position after the characters fed so far.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum State {
+    /// Leading whitespace only.
+    Start,
+    /// A sign, with no digits yet.
+    Sign,
+    /// Inside the numerator digits.
+    Numer,
+    /// After a `_` in the numerator.
+    NumerSep,
+    /// Whitespace after the numerator, before a possible `/`.
+    NumerSpace,
+    /// After the `/`, including any whitespace following it.
+    Slash,
+    /// Inside the denominator digits.
+    Denom,
+    /// After a `_` in the denominator.
+    DenomSep,
+    /// A `.` directly after numerator digits.
+    Point,
+    /// A `.` with no digits before it.
+    BarePoint,
+    /// Inside the fractional digits.
+    Decimal,
+    /// After a `_` in the fractional digits.
+    DecimalSep,
+    /// After the `e` or `E` exponent marker.
+    ExpMarker,
+    /// After the sign of the exponent.
+    ExpSign,
+    /// Inside the exponent digits.
+    Exp,
+    /// After a `_` in the exponent.
+    ExpSep,
+    /// Trailing whitespace after a complete number.
+    Trailing,
+}
+
+impl State {
+    /// Returns the state after consuming `c`, or `None` if `c` is not allowed here.
+    fn next(self, c: char) -> Option<State> {
+        use State::*;
+
+        let digit = c.is_ascii_digit();
+        let space = c.is_whitespace();
+        let sign = c == '+' || c == '-';
+        let exp = c == 'e' || c == 'E';
+
+        let next = match self {
+            Start if space => Start,
+            Start if sign => Sign,
+            Start | Sign if digit => Numer,
+            Start | Sign if c == '.' => BarePoint,
+            Numer | NumerSep if digit => Numer,
+            Numer if c == '_' => NumerSep,
+            Numer if c == '.' => Point,
+            Numer if c == '/' => Slash,
+            Numer if exp => ExpMarker,
+            Numer | NumerSpace if space => NumerSpace,
+            NumerSpace if c == '/' => Slash,
+            Slash if space => Slash,
+            Slash | Denom | DenomSep if digit => Denom,
+            Denom if c == '_' => DenomSep,
+            Point | BarePoint | Decimal | DecimalSep if digit => Decimal,
+            Decimal if c == '_' => DecimalSep,
+            Point | Decimal if exp => ExpMarker,
+            ExpMarker if sign => ExpSign,
+            ExpMarker | ExpSign | Exp | ExpSep if digit => Exp,
+            Exp if c == '_' => ExpSep,
+            Denom | Point | Decimal | Exp | Trailing if space => Trailing,
+            _ => return None,
+        };
+        Some(next)
+    }
+
+    /// Returns `true` if the input consumed so far forms a complete number.
+    fn is_complete(self) -> bool {
+        use State::*;
+        matches!(
+            self,
+            Numer | NumerSpace | Denom | Point | Decimal | Exp | Trailing
+        )
+    }
+}
+
+/// An incremental validator for rational number strings.
+///
+/// Characters are fed one at a time with [`feed`](PartialParser::feed), which
+/// reports whether each one is a valid continuation of the input so far.
+/// Rejected characters are not recorded, so the parser always holds a valid
+/// prefix. [`finish`](PartialParser::finish) parses the accepted input.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{FeedResult, PartialParser};
+///
+/// let mut parser = PartialParser::new();
+/// assert_eq!(parser.feed('3'), FeedResult::Complete);
+/// assert_eq!(parser.feed('/'), FeedResult::Accept);
+/// assert_eq!(parser.feed('x'), FeedResult::Reject);
+/// assert_eq!(parser.feed('4'), FeedResult::Complete);
+/// assert_eq!(parser.finish::<i32>().unwrap(), Ratio::new(3, 4));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PartialParser {
+    input: String,
+    state: State,
+}
+
+impl PartialParser {
+    /// Creates a parser with no input.
+    pub fn new() -> Self {
+        PartialParser {
+            input: String::new(),
+            state: State::Start,
+        }
+    }
+
+    /// Feeds the next character of the input.
+    pub fn feed(&mut self, c: char) -> FeedResult {
+        match self.state.next(c) {
+            Some(state) => {
+                self.state = state;
+                self.input.push(c);
+                if state.is_complete() {
+                    FeedResult::Complete
+                } else {
+                    FeedResult::Accept
+                }
+            }
+            None => FeedResult::Reject,
+        }
+    }
+
+    /// Returns `true` if the accepted input forms a complete number.
+    pub fn is_complete(&self) -> bool {
+        self.state.is_complete()
+    }
+
+    /// Returns the input accepted so far.
+    pub fn as_str(&self) -> &str {
+        &self.input
+    }
+
+    /// Parses the accepted input into a rational number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseRatioError`] if the input is incomplete, or for the same
+    /// reasons as [`RationalParse::from_str_flex`] (e.g. overflow or a zero
+    /// denominator).
+    pub fn finish<T: FlexInteger>(&self) -> Result<Ratio<T>, ParseRatioError> {
+        Ratio::<T>::from_str_flex(&self.input)
+    }
+}
+
+impl Default for PartialParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses a rational number from an iterator of characters.
+///
+/// This is useful for lexers that do not have the numeric region as a `&str`.
+/// The iterator is consumed entirely, and the result is identical to calling
+/// [`RationalParse::from_str_flex`] on the collected characters. Valid input is
+/// validated as it is consumed.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::from_chars_flex;
+///
+/// let r = from_chars_flex::<i32, _>("-1.25e1".chars()).unwrap();
+/// assert_eq!(r, Ratio::new(-25, 2));
+/// ```
+pub fn from_chars_flex<T, I>(iter: I) -> Result<Ratio<T>, ParseRatioError>
+where
+    T: FlexInteger,
+    I: IntoIterator<Item = char>,
+{
+    let mut parser = PartialParser::new();
+    let mut iter = iter.into_iter();
+    while let Some(c) = iter.next() {
+        if parser.feed(c) == FeedResult::Reject {
+            // Let the full parser pick the error, which depends on the rest of
+            // the input (e.g. a non-ASCII digit anywhere takes precedence).
+            let mut input = parser.input;
+            input.push(c);
+            input.extend(iter);
+            return Ratio::<T>::from_str_flex(&input);
+        }
+    }
+    parser.finish()
+}
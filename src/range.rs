@@ -0,0 +1,74 @@
+//! Parsing of rational intervals.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind, RationalParse};
+use num_rational::Ratio;
+
+/// Parses a range of two rationals separated by `..`, such as `"1/2..3/4"`.
+///
+/// Both endpoints accept the full grammar of [`RationalParse::from_str_flex`],
+/// including surrounding whitespace. The start must not be greater than the
+/// end; use [`parse_range_with`] for another separator or to allow reversed
+/// ranges.
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::ParseError`] if the separator is missing, the
+/// error of the first endpoint that fails to parse, and
+/// [`RatioErrorKind::InvalidRange`] if the start is greater than the end.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, parse_range};
+///
+/// let (start, end) = parse_range::<i32>("1/2..0.75").unwrap();
+/// assert_eq!((start, end), (Ratio::new(1, 2), Ratio::new(3, 4)));
+///
+/// let err = parse_range::<i32>("1..-1").unwrap_err();
+/// assert_eq!(*err.kind(), RatioErrorKind::InvalidRange);
+/// ```
+pub fn parse_range<T: FlexInteger>(s: &str) -> Result<(Ratio<T>, Ratio<T>), ParseRatioError> {
+    parse_range_with(s, "..", true)
+}
+
+/// Parses a range of two rationals separated by `separator`.
+///
+/// The input is split at the first occurrence of `separator`, so with `".."`,
+/// `"1...5"` is the range from `1` to `.5`. If `check_order` is `true`, a start
+/// greater than the end is rejected.
+///
+/// # Errors
+///
+/// Same as [`parse_range`]; [`RatioErrorKind::InvalidRange`] is only returned
+/// if `check_order` is `true`.
+///
+/// # Panics
+///
+/// Panics if `separator` is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::parse_range_with;
+///
+/// let (start, end) = parse_range_with::<i32>("3/4 to 1/4", "to", false).unwrap();
+/// assert_eq!((start, end), (Ratio::new(3, 4), Ratio::new(1, 4)));
+/// ```
+pub fn parse_range_with<T: FlexInteger>(
+    s: &str,
+    separator: &str,
+    check_order: bool,
+) -> Result<(Ratio<T>, Ratio<T>), ParseRatioError> {
+    assert!(!separator.is_empty(), "empty range separator");
+    let (start, end) = s
+        .split_once(separator)
+        .ok_or(ParseRatioError::new(RatioErrorKind::ParseError))?;
+    let start = Ratio::<T>::from_str_flex(start)?;
+    let end = Ratio::<T>::from_str_flex(end)?;
+    if check_order && start > end {
+        return Err(ParseRatioError::new(RatioErrorKind::InvalidRange));
+    }
+    Ok((start, end))
+}
@@ -0,0 +1,99 @@
+//! Rounding of rationals to a bounded precision.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind};
+use num_rational::Ratio;
+
+/// Rounds `r` to `places` decimal places, resolving ties to the even neighbor.
+///
+/// The result is the multiple of `10^-places` nearest to `r`. If `r` lies
+/// exactly halfway between two of them, the one with an even last digit is
+/// chosen (banker's rounding), which avoids a systematic bias when many values
+/// are rounded. For ties away from zero, use [`round_to_decimals_half_up`].
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::Overflow`] if `10^places` or the scaled value does
+/// not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::round_to_decimals;
+///
+/// assert_eq!(round_to_decimals(&Ratio::new(1, 3), 2).unwrap(), Ratio::new(33, 100));
+/// assert_eq!(round_to_decimals(&Ratio::new(5, 8), 2).unwrap(), Ratio::new(31, 50));
+/// assert_eq!(round_to_decimals(&Ratio::new(-5, 2), 0).unwrap(), Ratio::from_integer(-2));
+/// ```
+pub fn round_to_decimals<T: FlexInteger>(
+    r: &Ratio<T>,
+    places: u32,
+) -> Result<Ratio<T>, ParseRatioError> {
+    round_scaled(r, places, |floor| floor.is_odd())
+}
+
+/// Rounds `r` to `places` decimal places, resolving ties away from zero.
+///
+/// This is the rounding taught in school and used by many financial rules:
+/// `0.125` to two places is `0.13`, and `-0.125` is `-0.13`. Otherwise it
+/// behaves like [`round_to_decimals`].
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::Overflow`] if `10^places` or the scaled value does
+/// not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::round_to_decimals_half_up;
+///
+/// let r = Ratio::new(5, 8);
+/// assert_eq!(round_to_decimals_half_up(&r, 2).unwrap(), Ratio::new(63, 100));
+/// assert_eq!(round_to_decimals_half_up(&-r, 2).unwrap(), Ratio::new(-63, 100));
+/// ```
+pub fn round_to_decimals_half_up<T: FlexInteger>(
+    r: &Ratio<T>,
+    places: u32,
+) -> Result<Ratio<T>, ParseRatioError> {
+    round_scaled(r, places, |floor| !floor.is_negative())
+}
+
+/// Rounds `r * 10^places` to an integer and divides it by `10^places` again.
+///
+/// `round_tie_up` decides whether a value exactly halfway above `floor` is
+/// rounded up to `floor + 1`.
+fn round_scaled<T: FlexInteger>(
+    r: &Ratio<T>,
+    places: u32,
+    round_tie_up: impl Fn(&T) -> bool,
+) -> Result<Ratio<T>, ParseRatioError> {
+    let overflow = ParseRatioError::new(RatioErrorKind::Overflow);
+    let ten = T::from_u8(10).ok_or(overflow)?;
+    let scale = num_traits::checked_pow(ten, places as usize).ok_or(overflow)?;
+
+    // Reduce before multiplying, as `Ratio::checked_mul` does not.
+    let gcd = scale.gcd(r.denom());
+    let numer = r
+        .numer()
+        .checked_mul(&(scale.clone() / gcd.clone()))
+        .ok_or(overflow)?;
+    let denom = r.denom().clone() / gcd;
+
+    // Compare the remainder with its distance to the next integer instead of
+    // doubling it, which could overflow.
+    let (floor, rem) = numer.div_mod_floor(&denom);
+    let gap = denom - rem.clone();
+    let round_up = match rem.cmp(&gap) {
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => round_tie_up(&floor),
+    };
+    let rounded = if round_up {
+        floor.checked_add(&T::one()).ok_or(overflow)?
+    } else {
+        floor
+    };
+    Ok(Ratio::new(rounded, scale))
+}
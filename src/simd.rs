@@ -0,0 +1,57 @@
+//! Fast parsing of long digit runs, enabled by the `simd` feature.
+//!
+//! Eight digits at a time are converted with a few integer operations on a
+//! `u64` (SIMD within a register), which is portable and needs no `unsafe` or
+//! CPU feature detection.
+
+use crate::FlexInteger;
+
+/// Digit runs shorter than this are parsed by the scalar path.
+const MIN_LEN: usize = 16;
+
+/// Converts eight ASCII digits, most significant first, to their value.
+fn parse_eight(chunk: &[u8]) -> u32 {
+    let bytes: [u8; 8] = chunk.try_into().expect("chunk of eight digits");
+    let mut v = u64::from_le_bytes(bytes).wrapping_sub(0x3030_3030_3030_3030);
+    // Combine adjacent digits into 2-digit, then 4-digit, then 8-digit values.
+    v = v.wrapping_mul(10).wrapping_add(v >> 8);
+    const MASK: u64 = 0x0000_00ff_0000_00ff;
+    const MUL_HI: u64 = 100 + (1_000_000 << 32);
+    const MUL_LO: u64 = 1 + (10_000 << 32);
+    v = (v & MASK)
+        .wrapping_mul(MUL_HI)
+        .wrapping_add(((v >> 16) & MASK).wrapping_mul(MUL_LO))
+        >> 32;
+    v as u32
+}
+
+/// Parses a run of ASCII digits eight at a time.
+///
+/// Returns `None` if the fast path does not apply (a short run, or a type too
+/// narrow to hold eight digits), and `Some(None)` if the value overflows `T`.
+/// The value grows with every step, so it overflows exactly when `T::from_str`
+/// would fail.
+pub(crate) fn parse_long_digits<T: FlexInteger>(digits: &str) -> Option<Option<T>> {
+    let bytes = digits.as_bytes();
+    if bytes.len() < MIN_LEN {
+        return None;
+    }
+    let chunk_scale = T::from_u32(100_000_000)?;
+    let (head, chunks) = bytes.split_at(bytes.len() % 8);
+
+    let accumulate = || -> Option<T> {
+        let ten = T::from_u8(10)?;
+        let mut value = T::zero();
+        for &b in head {
+            value = value
+                .checked_mul(&ten)?
+                .checked_add(&T::from_u8(b - b'0')?)?;
+        }
+        for chunk in chunks.chunks_exact(8) {
+            let low = T::from_u32(parse_eight(chunk))?;
+            value = value.checked_mul(&chunk_scale)?.checked_add(&low)?;
+        }
+        Some(value)
+    };
+    Some(accumulate())
+}
@@ -0,0 +1,67 @@
+//! Small utilities built on the parser, enabled by the `util` feature.
+
+use crate::{FlexInteger, ParseRatioError, RatioErrorKind, RationalParse};
+use num_rational::Ratio;
+
+/// Attempts to parse a string into a ratio of every built-in signed integer type.
+///
+/// Expands to an array of `(type_name, Result<(), ParseRatioError>)` pairs for
+/// `i8`, `i16`, `i32`, `i64` and `i128`, in that order. The first successful
+/// entry is the smallest type that can represent the input.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational_parse::try_all_int_types;
+///
+/// let results = try_all_int_types!("1/1000");
+/// let smallest = results.iter().find(|(_, r)| r.is_ok()).map(|(name, _)| *name);
+/// assert_eq!(smallest, Some("i16"));
+/// ```
+#[macro_export]
+macro_rules! try_all_int_types {
+    ($s:expr) => {{
+        let s: &str = $s;
+        [
+            ("i8", $crate::validate_flex::<i8>(s)),
+            ("i16", $crate::validate_flex::<i16>(s)),
+            ("i32", $crate::validate_flex::<i32>(s)),
+            ("i64", $crate::validate_flex::<i64>(s)),
+            ("i128", $crate::validate_flex::<i128>(s)),
+        ]
+    }};
+}
+
+/// Parses the value of `key` in a line of `key=value` pairs.
+///
+/// Pairs are separated by `;` or `,`, and whitespace around keys, `=` and values
+/// is ignored. Keys are matched exactly; if one occurs several times, the first
+/// occurrence is used. The value is parsed with [`RationalParse::from_str_flex`].
+///
+/// # Errors
+///
+/// Returns [`RatioErrorKind::KeyNotFound`] if no pair has the key, and the parse
+/// error of its value otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use num_rational_parse::{RatioErrorKind, from_kv};
+///
+/// let line = "ratio = 3/4; other=5";
+/// assert_eq!(from_kv::<i32>(line, "ratio").unwrap(), Ratio::new(3, 4));
+/// assert_eq!(
+///     *from_kv::<i32>(line, "missing").unwrap_err().kind(),
+///     RatioErrorKind::KeyNotFound
+/// );
+/// ```
+pub fn from_kv<T: FlexInteger>(line: &str, key: &str) -> Result<Ratio<T>, ParseRatioError> {
+    let value = line
+        .split([';', ','])
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, value)| value)
+        .ok_or(ParseRatioError::new(RatioErrorKind::KeyNotFound))?;
+    Ratio::<T>::from_str_flex(value)
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "cache")]
+
+use num_rational::{Ratio, Rational32};
+use num_rational_parse::{CachedParser, RatioErrorKind, RationalParse};
+use std::num::NonZeroUsize;
+
+fn parser(capacity: usize) -> CachedParser<i32> {
+    CachedParser::new(NonZeroUsize::new(capacity).unwrap())
+}
+
+#[test]
+fn test_matches_from_str_flex() {
+    let parser = parser(4);
+    for s in [
+        "3/4",
+        "-1.5e2",
+        " 0013/002 ",
+        "1/0",
+        "x",
+        "2147483648",
+        "3/4",
+    ] {
+        for _ in 0..2 {
+            assert_eq!(
+                parser.parse(s),
+                Rational32::from_str_flex(s),
+                "input {:?}",
+                s
+            );
+        }
+    }
+    assert_eq!(
+        *parser.parse("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_eviction() {
+    let parser = parser(2);
+    assert!(parser.is_empty());
+    assert_eq!(parser.capacity().get(), 2);
+
+    parser.parse("1").unwrap();
+    parser.parse("2").unwrap();
+    // Touch "1" so that "2" is the least recently used entry.
+    parser.parse("1").unwrap();
+    parser.parse("3").unwrap();
+    assert_eq!(parser.len(), 2);
+
+    // Each distinct spelling is its own entry.
+    assert_eq!(parser.parse("0.5").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parser.parse("1/2").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parser.len(), 2);
+
+    parser.clear();
+    assert!(parser.is_empty());
+    assert_eq!(parser.parse("3").unwrap(), Ratio::new(3, 1));
+}
+
+#[test]
+fn test_shared_between_threads() {
+    let parser = parser(8);
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for i in 0..100 {
+                    let s = format!("{}/4", i % 10);
+                    assert_eq!(parser.parse(&s).unwrap(), Ratio::new(i % 10, 4));
+                }
+            });
+        }
+    });
+    assert!(parser.len() <= 8);
+}
@@ -0,0 +1,31 @@
+use num_rational::Rational32;
+use num_rational_parse::RationalParse;
+
+const CORPUS: &str = include_str!("data/corpus.tsv");
+
+#[test]
+fn test_corpus() {
+    let mut failures = Vec::new();
+    for (number, line) in CORPUS.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (input, expected) = line
+            .split_once('\t')
+            .unwrap_or_else(|| panic!("line {} has no tab: {:?}", number + 1, line));
+        let actual = match Rational32::from_str_flex(input) {
+            Ok(r) => format!("{}/{}", r.numer(), r.denom()),
+            Err(e) => format!("{:?}", e.kind()),
+        };
+        if actual != expected {
+            failures.push(format!(
+                "line {}: {:?} gave {}, expected {}",
+                number + 1,
+                input,
+                actual,
+                expected
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
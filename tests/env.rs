@@ -0,0 +1,38 @@
+#![cfg(feature = "env")]
+
+use num_rational::Ratio;
+use num_rational_parse::{RatioErrorKind, from_env_or};
+use std::env;
+
+#[test]
+fn test_from_env_or() {
+    let default = Ratio::<i32>::new(1, 2);
+
+    env::remove_var("NUM_RATIONAL_PARSE_TEST_UNSET");
+    assert_eq!(
+        from_env_or("NUM_RATIONAL_PARSE_TEST_UNSET", default).unwrap(),
+        default
+    );
+
+    env::set_var("NUM_RATIONAL_PARSE_TEST_VALID", " 0.75 ");
+    assert_eq!(
+        from_env_or("NUM_RATIONAL_PARSE_TEST_VALID", default).unwrap(),
+        Ratio::new(3, 4)
+    );
+
+    env::set_var("NUM_RATIONAL_PARSE_TEST_INVALID", "3/0");
+    assert_eq!(
+        *from_env_or("NUM_RATIONAL_PARSE_TEST_INVALID", default)
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+
+    env::set_var("NUM_RATIONAL_PARSE_TEST_EMPTY", "");
+    assert_eq!(
+        *from_env_or("NUM_RATIONAL_PARSE_TEST_EMPTY", default)
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::ParseError
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "error-input")]
+
+use num_rational::{Ratio, Rational32};
+use num_rational_parse::{
+    ParseRatioError, RatioErrorKind, RationalParse, RationalParser, from_fixed_point,
+    from_percent_change, from_percent_of, from_space_pair, from_str_flex_integer,
+};
+
+#[test]
+fn test_error_input() {
+    let err = Rational32::from_str_flex(" 1/0 ").unwrap_err();
+    assert_eq!(err.input(), Some(" 1/0 "));
+    assert_eq!(*err.kind(), RatioErrorKind::ZeroDenominator);
+
+    let err = RationalParser::new()
+        .require_sign(true)
+        .parse::<i32>("3/2")
+        .unwrap_err();
+    assert_eq!(err.input(), Some("3/2"));
+    assert_eq!(Rational32::from_str_flex("").unwrap_err().input(), Some(""));
+
+    // Helpers that rewrite their input record it as given.
+    let err = from_fixed_point::<i32>("  12.5", 2).unwrap_err();
+    assert_eq!(err.input(), Some("  12.5"));
+    let err = from_percent_of("200 %", Ratio::<i8>::from_integer(100)).unwrap_err();
+    assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+    assert_eq!(err.input(), Some("200 %"));
+    let err = from_percent_change::<i32>("5").unwrap_err();
+    assert_eq!(err.input(), Some("5"));
+    let err = from_space_pair::<i32>(" 3 0 ").unwrap_err();
+    assert_eq!(err.input(), Some(" 3 0 "));
+
+    // Errors raised outside of the parser carry no input.
+    let err = from_str_flex_integer::<i32>("3/2").unwrap_err();
+    assert_eq!(*err.kind(), RatioErrorKind::NonInteger);
+    assert_eq!(err.input(), None);
+    assert_eq!(
+        ParseRatioError::from(RatioErrorKind::ParseError).input(),
+        None
+    );
+}
+
+#[test]
+fn test_error_input_truncated() {
+    let long = "1".repeat(100);
+    let err = Rational32::from_str_flex(&long).unwrap_err();
+    assert_eq!(err.input(), Some(&long[..ParseRatioError::MAX_INPUT_LEN]));
+
+    // The cut never splits a character.
+    let input = format!("{}é", "x".repeat(ParseRatioError::MAX_INPUT_LEN - 1));
+    let err = Rational32::from_str_flex(&input).unwrap_err();
+    assert_eq!(
+        err.input(),
+        Some(&input[..ParseRatioError::MAX_INPUT_LEN - 1])
+    );
+}
+
+#[test]
+fn test_error_input_not_compared() {
+    let a = Rational32::from_str_flex("x").unwrap_err();
+    let b = Rational32::from_str_flex("y").unwrap_err();
+    assert_eq!(a, b);
+    assert_eq!(a, ParseRatioError::from(RatioErrorKind::ParseError));
+}
+
+#[test]
+fn test_error_input_len() {
+    let input = " 12345/0 ";
+    let parse = |len: usize| {
+        RationalParser::new()
+            .error_input_len(len)
+            .parse::<i32>(input)
+            .unwrap_err()
+    };
+    assert_eq!(parse(4).input(), Some(" 123"));
+    assert_eq!(parse(input.len()).input(), Some(input));
+    assert_eq!(parse(1000).input(), Some(input));
+    assert_eq!(parse(0).input(), None);
+    assert_eq!(*parse(0).kind(), RatioErrorKind::ZeroDenominator);
+
+    // The limit cannot exceed the inline capacity.
+    let long = "1".repeat(200);
+    let err = RationalParser::new()
+        .error_input_len(usize::MAX)
+        .parse::<i32>(&long)
+        .unwrap_err();
+    assert_eq!(err.input(), Some(&long[..ParseRatioError::MAX_INPUT_LEN]));
+
+    // A short limit never splits a character.
+    let err = RationalParser::new()
+        .error_input_len(2)
+        .parse::<i32>("é1")
+        .unwrap_err();
+    assert_eq!(err.input(), Some("é"));
+    let err = RationalParser::new()
+        .error_input_len(1)
+        .parse::<i32>("é1")
+        .unwrap_err();
+    assert_eq!(err.input(), Some(""));
+}
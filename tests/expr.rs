@@ -0,0 +1,70 @@
+use num_rational::Ratio;
+use num_rational_parse::{RatioErrorKind, from_product, from_sum};
+
+#[test]
+fn test_sum() {
+    let sum = |s: &str| from_sum::<i32>(s).unwrap();
+
+    assert_eq!(sum("1/2 + 1/3 - 1/6"), Ratio::new(2, 3));
+    assert_eq!(sum("3/4"), Ratio::new(3, 4));
+    assert_eq!(sum("-1/2+1/2"), Ratio::new(0, 1));
+    assert_eq!(sum("1 - -1/2"), Ratio::new(3, 2));
+    assert_eq!(sum("1e-1 + 1E+1 - .5"), Ratio::new(48, 5));
+    assert_eq!(sum(" 1_000 - 1.5e2 "), Ratio::new(850, 1));
+}
+
+#[test]
+fn test_sum_invalid() {
+    let kind = |s: &str| *from_sum::<i32>(s).unwrap_err().kind();
+
+    assert_eq!(kind(""), RatioErrorKind::ParseError);
+    assert_eq!(kind("1 +"), RatioErrorKind::ParseError);
+    assert_eq!(kind("+ 1"), RatioErrorKind::ParseError);
+    assert_eq!(kind("1 + - 1"), RatioErrorKind::ParseError);
+    assert_eq!(kind("1 * 2"), RatioErrorKind::ParseError);
+    assert_eq!(kind("1e +3"), RatioErrorKind::DanglingExponent);
+    assert_eq!(kind("1/2 + 1/0"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind("2147483647 + 1"), RatioErrorKind::Overflow);
+    assert_eq!(
+        from_sum::<i32>("2147483647 + 1")
+            .unwrap_err()
+            .overflow_source(),
+        None
+    );
+    assert_eq!(
+        kind("1/2147483647 + 1/2147483646"),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_product() {
+    let product = |s: &str| from_product::<i32>(s).unwrap();
+
+    assert_eq!(product("3/4 * 2/5"), Ratio::new(3, 10));
+    assert_eq!(product("3/4"), Ratio::new(3, 4));
+    assert_eq!(product("1.5×2e1"), Ratio::new(30, 1));
+    assert_eq!(product("1/2 * -4 × -1/3"), Ratio::new(2, 3));
+    assert_eq!(product(" 1_000 * 1e-3 "), Ratio::new(1, 1));
+    assert_eq!(product("0 * 2147483647 * 2147483647"), Ratio::new(0, 1));
+    // Intermediate results are reduced, so this does not overflow.
+    assert_eq!(product("2147483647 * 1/2147483647"), Ratio::new(1, 1));
+}
+
+#[test]
+fn test_product_invalid() {
+    let kind = |s: &str| *from_product::<i32>(s).unwrap_err().kind();
+
+    assert_eq!(kind(""), RatioErrorKind::ParseError);
+    assert_eq!(kind("2 *"), RatioErrorKind::ParseError);
+    assert_eq!(kind("2 ** 3"), RatioErrorKind::ParseError);
+    assert_eq!(kind("2 * 3 + 1"), RatioErrorKind::ParseError);
+    assert_eq!(kind("0 * 1/0"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind("65536 * 65536"), RatioErrorKind::Overflow);
+    assert_eq!(
+        from_product::<i32>("65536 * 65536")
+            .unwrap_err()
+            .overflow_source(),
+        None
+    );
+}
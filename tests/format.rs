@@ -0,0 +1,197 @@
+use num_rational::{Ratio, Rational64};
+use num_rational_parse::{
+    MAX_SIG_FIGS, RationalParse, to_normalized_scientific, to_scientific_string, to_sig_figs_string,
+};
+
+#[test]
+fn test_scientific_string() {
+    let sci = |n: i32, d: i32, sig: usize| to_scientific_string(&Ratio::new(n, d), sig);
+
+    assert_eq!(sci(1, 8, 3).unwrap(), "1.25e-1");
+    assert_eq!(sci(1, 8, 5).unwrap(), "1.2500e-1");
+    assert_eq!(sci(1, 8, 2), None);
+    assert_eq!(sci(12300, 1, 3).unwrap(), "1.23e4");
+    assert_eq!(sci(12300, 1, 2), None);
+    assert_eq!(sci(-47, 100, 2).unwrap(), "-4.7e-1");
+    assert_eq!(sci(3, 1, 1).unwrap(), "3e0");
+    assert_eq!(sci(1, 3125, 2).unwrap(), "3.2e-4");
+    assert_eq!(sci(0, 1, 3).unwrap(), "0.00e0");
+    assert_eq!(sci(1, 3, 20), None);
+    assert_eq!(sci(1, 2, 0), None);
+    assert_eq!(sci(1234567, 500, 9).unwrap(), "2.46913400e3");
+
+    // The extremes of the backing type do not overflow.
+    assert_eq!(
+        to_scientific_string(&Ratio::<i8>::new_raw(-128, 1), 3).unwrap(),
+        "-1.28e2"
+    );
+    assert_eq!(to_scientific_string(&Ratio::<i8>::new(1, 127), 1), None);
+    assert_eq!(
+        to_scientific_string(&Ratio::<i8>::new(1, 125), 1).unwrap(),
+        "8e-3"
+    );
+}
+
+#[test]
+fn test_scientific_string_many_digits() {
+    let sci = |n: i32, d: i32, sig: usize| to_scientific_string(&Ratio::new(n, d), sig);
+
+    assert_eq!(sci(1, 3, usize::MAX), None);
+    assert_eq!(sci(-2, 7, usize::MAX), None);
+    assert_eq!(sci(1, 8, usize::MAX), None);
+    assert_eq!(sci(0, 1, usize::MAX), None);
+    assert_eq!(sci(12300, 1, usize::MAX / 2), None);
+
+    let out = sci(1, 8, 1000).unwrap();
+    assert_eq!(out.len(), 1001 + 3);
+    assert!(out.starts_with("1.25000") && out.ends_with("0e-1"));
+    assert_eq!(sci(0, 1, 1000).unwrap().len(), 1001 + 2);
+    assert_eq!(
+        Rational64::from_str_flex(&sci(-12300, 1, 1000).unwrap()).unwrap(),
+        Ratio::from_integer(-12300)
+    );
+}
+
+#[test]
+fn test_scientific_round_trip() {
+    for s in ["3.1415", "-47e-2", "1e-9", "-2147483647", "123/1024"] {
+        let r = Rational64::from_str_flex(s).unwrap();
+        let out = to_scientific_string(&r, 12).unwrap();
+        assert_eq!(Rational64::from_str_flex(&out).unwrap(), r, "input {:?}", s);
+    }
+}
+
+#[test]
+fn test_normalized_scientific() {
+    let exact = |n: i64, d: i64| to_normalized_scientific(&Ratio::new(n, d), None);
+    let approx = |n: i64, d: i64, p: usize| to_normalized_scientific(&Ratio::new(n, d), Some(p));
+
+    assert_eq!(exact(12300, 1).unwrap(), "1.23e4");
+    assert_eq!(exact(1, 8).unwrap(), "1.25e-1");
+    assert_eq!(exact(-47, 100).unwrap(), "-4.7e-1");
+    assert_eq!(exact(3, 1).unwrap(), "3e0");
+    assert_eq!(exact(10, 1).unwrap(), "1e1");
+    assert_eq!(exact(0, 1).unwrap(), "0e0");
+    assert_eq!(exact(1, 3125).unwrap(), "3.2e-4");
+    assert_eq!(exact(1234567, 500).unwrap(), "2.469134e3");
+    assert_eq!(exact(1, 3), None);
+    assert_eq!(exact(1, 6), None);
+
+    assert_eq!(approx(1, 3, 4).unwrap(), "3.333e-1");
+    assert_eq!(approx(2, 3, 3).unwrap(), "6.67e-1");
+    assert_eq!(approx(-2, 3, 1).unwrap(), "-7e-1");
+    assert_eq!(approx(12345, 1, 2).unwrap(), "1.2e4");
+    assert_eq!(approx(12500, 1, 2).unwrap(), "1.2e4");
+    assert_eq!(approx(13500, 1, 2).unwrap(), "1.4e4");
+    assert_eq!(approx(12501, 1, 2).unwrap(), "1.3e4");
+    assert_eq!(approx(9999, 1, 3).unwrap(), "1e4");
+    assert_eq!(approx(1999, 10000, 3).unwrap(), "2e-1");
+    assert_eq!(approx(1, 8, 10).unwrap(), "1.25e-1");
+    assert_eq!(approx(1, 3, 0), None);
+
+    // The output must parse back into the same type.
+    assert_eq!(
+        to_normalized_scientific(&Ratio::<i8>::new(1, 125), None),
+        None
+    );
+    assert_eq!(
+        to_normalized_scientific(&Ratio::<i8>::new_raw(-128, 1), None).unwrap(),
+        "-1.28e2"
+    );
+}
+
+#[test]
+fn test_normalized_scientific_round_trip() {
+    for s in [
+        "3.1415",
+        "-47e-2",
+        "1e-9",
+        "-2147483647",
+        "123/1024",
+        "0.000",
+    ] {
+        let r = Rational64::from_str_flex(s).unwrap();
+        let out = to_normalized_scientific(&r, None).unwrap();
+        assert_eq!(Rational64::from_str_flex(&out).unwrap(), r, "input {:?}", s);
+        assert_eq!(to_normalized_scientific(&r, Some(20)).unwrap(), out);
+    }
+}
+
+#[test]
+fn test_sig_figs_string() {
+    let figs = |n: i64, d: i64, f: u32| to_sig_figs_string(&Ratio::new(n, d), f);
+
+    assert_eq!(figs(1, 3, 3), "0.333");
+    assert_eq!(figs(2, 3, 3), "0.667");
+    assert_eq!(figs(-2, 3, 1), "-0.7");
+    assert_eq!(figs(1, 2, 3), "0.500");
+    assert_eq!(figs(1234, 1, 2), "1.2e3");
+    assert_eq!(figs(1234, 1, 4), "1234");
+    assert_eq!(figs(1234, 1, 6), "1234.00");
+    assert_eq!(figs(1200, 1, 4), "1200");
+    assert_eq!(figs(12, 1, 2), "12");
+    assert_eq!(figs(3, 1, 1), "3");
+    assert_eq!(figs(123456, 1000, 4), "123.5");
+
+    // Ties round to even.
+    assert_eq!(figs(125, 1000, 2), "0.12");
+    assert_eq!(figs(135, 1000, 2), "0.14");
+    assert_eq!(figs(12501, 100000, 2), "0.13");
+    assert_eq!(figs(25, 1, 1), "2e1");
+
+    // Rounding can carry into a new digit and change the form.
+    assert_eq!(figs(9999, 1, 3), "1.00e4");
+    assert_eq!(figs(999, 1, 3), "999");
+    assert_eq!(figs(9996, 10, 3), "1.00e3");
+    assert_eq!(figs(99996, 1000000, 3), "0.100");
+
+    // The thresholds of the decimal form.
+    assert_eq!(figs(1, 10000, 2), "0.00010");
+    assert_eq!(figs(1, 100000, 2), "1.0e-5");
+    assert_eq!(figs(-123, 100000000, 3), "-1.23e-6");
+
+    assert_eq!(figs(0, 1, 3), "0.00");
+    assert_eq!(figs(0, 1, 1), "0");
+
+    assert_eq!(
+        to_sig_figs_string(&Ratio::<i8>::new_raw(-128, 1), 2),
+        "-1.3e2"
+    );
+}
+
+#[test]
+fn test_sig_figs_round_trip() {
+    for s in ["3.1415", "-47e-2", "1e-9", "-2147483647", "123/1024", "0"] {
+        let r = Rational64::from_str_flex(s).unwrap();
+        let out = to_sig_figs_string(&r, 12);
+        assert_eq!(Rational64::from_str_flex(&out).unwrap(), r, "input {:?}", s);
+    }
+}
+
+#[test]
+fn test_sig_figs_many_figures() {
+    let max = MAX_SIG_FIGS as usize;
+    let figs = |n: i64, d: i64, f: u32| to_sig_figs_string(&Ratio::new(n, d), f);
+
+    let third = figs(1, 3, u32::MAX);
+    assert_eq!(third.len(), max + 2);
+    assert!(third.starts_with("0.333") && third.ends_with("333"));
+    assert_eq!(third, figs(1, 3, MAX_SIG_FIGS));
+
+    let half = figs(-1, 2, u32::MAX);
+    assert_eq!(half.len(), max + 3);
+    assert!(half.starts_with("-0.5000") && half.ends_with('0'));
+    assert_eq!(figs(0, 1, u32::MAX).len(), max + 1);
+    assert_eq!(figs(1234, 1, u32::MAX).len(), max + 1);
+
+    // Just below the limit nothing changes.
+    let just_below = figs(2, 3, MAX_SIG_FIGS - 1);
+    assert_eq!(just_below.len(), max + 1);
+    assert!(just_below.ends_with('7'));
+}
+
+#[test]
+#[should_panic]
+fn test_sig_figs_zero() {
+    to_sig_figs_string(&Ratio::new(1, 3), 0);
+}
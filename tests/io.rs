@@ -0,0 +1,48 @@
+#![cfg(feature = "std")]
+
+use num_rational::Ratio;
+use num_rational_parse::{MAX_PREFIXED_LEN, RatioErrorKind, from_length_prefixed};
+
+#[test]
+fn test_from_length_prefixed() {
+    fn record(text: &[u8]) -> Vec<u8> {
+        let mut out = (text.len() as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(text);
+        out
+    }
+
+    let mut stream = [record(b"1/2"), record(b" -0.25 "), record(b"3e2")].concat();
+    stream.extend(record(b"1/0"));
+    stream.extend(record(b"5"));
+    let mut reader = &stream[..];
+    let mut next = || from_length_prefixed::<i32, _>(&mut reader).map_err(|e| *e.kind());
+    assert_eq!(next(), Ok(Ratio::new(1, 2)));
+    assert_eq!(next(), Ok(Ratio::new(-1, 4)));
+    assert_eq!(next(), Ok(Ratio::from_integer(300)));
+    // A bad value does not break the framing.
+    assert_eq!(next(), Err(RatioErrorKind::ZeroDenominator));
+    assert_eq!(next(), Ok(Ratio::from_integer(5)));
+    // The end of the stream.
+    assert_eq!(next(), Err(RatioErrorKind::ParseError));
+
+    let parse =
+        |bytes: &[u8]| from_length_prefixed::<i32, _>(&mut &bytes[..]).map_err(|e| *e.kind());
+    assert_eq!(parse(&record(b"")), Err(RatioErrorKind::ParseError));
+    assert_eq!(parse(&record(b"\xff")), Err(RatioErrorKind::ParseError));
+    assert_eq!(
+        parse(&record(b"99999999999")),
+        Err(RatioErrorKind::Overflow)
+    );
+    // Truncated prefix and payload.
+    assert_eq!(parse(b"\0\0"), Err(RatioErrorKind::ParseError));
+    assert_eq!(parse(b"\0\0\0\x041/2"), Err(RatioErrorKind::ParseError));
+
+    // The length is checked before anything is allocated.
+    let huge = u32::MAX.to_be_bytes();
+    assert_eq!(parse(&huge), Err(RatioErrorKind::ParseError));
+    let mut long = record(&vec![b'0'; MAX_PREFIXED_LEN as usize]);
+    assert_eq!(parse(&long), Ok(Ratio::from_integer(0)));
+    long[3] += 1;
+    long.push(b'0');
+    assert_eq!(parse(&long), Err(RatioErrorKind::ParseError));
+}
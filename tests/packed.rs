@@ -0,0 +1,84 @@
+#![cfg(feature = "packed")]
+
+use num_bigint::BigInt;
+use num_rational::Ratio;
+use num_rational_parse::{RatioErrorKind, from_bytes_packed, to_bytes_packed};
+
+#[test]
+fn test_round_trip() {
+    for (n, d) in [
+        (0, 1),
+        (1, 1),
+        (-1, 1),
+        (63, 64),
+        (-64, 65),
+        (127, 128),
+        (-128, 129),
+        (-300, 7),
+        (i64::MAX, 1),
+        (i64::MIN, 1),
+        (1, i64::MAX),
+    ] {
+        let r = Ratio::new(n, d);
+        let bytes = to_bytes_packed(&r);
+        assert_eq!(
+            from_bytes_packed(&bytes).unwrap(),
+            (r, bytes.len()),
+            "{}",
+            r
+        );
+    }
+
+    let big = Ratio::new(BigInt::from(-7) << 200, BigInt::from(3) << 100);
+    let bytes = to_bytes_packed(&big);
+    assert_eq!(from_bytes_packed(&bytes).unwrap(), (big, bytes.len()));
+}
+
+#[test]
+fn test_encoding() {
+    assert_eq!(to_bytes_packed(&Ratio::new(0i32, 1)), [0x00, 0x01]);
+    assert_eq!(to_bytes_packed(&Ratio::new(-3i32, 4)), [0x7d, 0x04]);
+    assert_eq!(
+        to_bytes_packed(&Ratio::new(64i32, 129)),
+        [0xc0, 0x00, 0x81, 0x01]
+    );
+    assert_eq!(to_bytes_packed(&Ratio::new(-128i8, 1)), [0x80, 0x7f, 0x01]);
+    assert_eq!(to_bytes_packed(&Ratio::new_raw(6i32, 4)), [0x06, 0x04]);
+}
+
+#[test]
+fn test_decode() {
+    // Values are reduced, and only the first ratio is read.
+    assert_eq!(
+        from_bytes_packed::<i32>(&[0x06, 0x04, 0x01, 0x02]).unwrap(),
+        (Ratio::new(3, 2), 2)
+    );
+    // The full range of `i8` decodes without overflowing intermediates.
+    assert_eq!(
+        from_bytes_packed::<i8>(&[0x80, 0x7f, 0x01]).unwrap(),
+        (Ratio::new(-128, 1), 3)
+    );
+    assert_eq!(
+        from_bytes_packed::<i8>(&[0xff, 0x00, 0x01]).unwrap(),
+        (Ratio::new(127, 1), 3)
+    );
+    // Redundant continuation bytes are accepted.
+    assert_eq!(
+        from_bytes_packed::<i32>(&[0x85, 0x80, 0x00, 0x81, 0x00]).unwrap(),
+        (Ratio::new(5, 1), 5)
+    );
+}
+
+#[test]
+fn test_decode_invalid() {
+    let kind = |bytes: &[u8]| *from_bytes_packed::<i8>(bytes).unwrap_err().kind();
+
+    assert_eq!(kind(&[]), RatioErrorKind::ParseError);
+    assert_eq!(kind(&[0x01]), RatioErrorKind::ParseError);
+    assert_eq!(kind(&[0x81]), RatioErrorKind::ParseError);
+    assert_eq!(kind(&[0x01, 0x82]), RatioErrorKind::ParseError);
+    assert_eq!(kind(&[0x01, 0x00]), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind(&[0x80, 0x01, 0x01]), RatioErrorKind::Overflow);
+    assert_eq!(kind(&[0xff, 0x7e, 0x01]), RatioErrorKind::Overflow);
+    assert_eq!(kind(&[0x01, 0x80, 0x01]), RatioErrorKind::Overflow);
+}
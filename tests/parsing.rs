@@ -1,5 +1,17 @@
 use num_rational::{Ratio, Rational32, Rational64};
-use num_rational_parse::{RatioErrorKind, RationalParse};
+use num_rational_parse::{
+    ExcessDigits, FlexInteger, FlexRatio, FormatClass, OverflowMode, OverflowSource,
+    ParseRatioError, ParseReport, ParsedNumber, RangePolicy, RatioErrorKind, RationalParse,
+    RationalParser, Trimmed, ZeroDenomPolicy, classify_flex, compare_flex, denominator_factors,
+    from_bytes_flex, from_currency, from_fixed_point, from_percent_change, from_percent_of,
+    from_space_pair, from_str_flex_all_errors, from_str_flex_as_int, from_str_flex_canonical,
+    from_str_flex_clamped01, from_str_flex_default_denom, from_str_flex_integer,
+    from_str_flex_or_else, from_str_flex_reciprocal, from_str_flex_typed, from_str_flex_unsigned,
+    from_str_flex_with_allowed_denoms, from_str_flex_with_gcd, parse_csv_column, parse_flex_f64,
+    parse_flex_max, parse_flex_min, parse_flex_trimmed, parse_flex_widest, parse_number,
+    parse_whitespace_separated, ratio_from_parts, ratio_stats, sign, snap_to_nice,
+    to_egyptian_fraction, validate_all, validate_flex, widen,
+};
 
 fn components(s: &str) -> (i32, i32) {
     let r = Rational32::from_str_flex(s).unwrap_or_else(|_| panic!("Failed to parse {}", s));
@@ -42,6 +54,26 @@ fn test_integers() {
     assert_eq!((123, 1), components("1_2_3"));
 }
 
+#[test]
+fn test_leading_zeros() {
+    assert_eq!((0, 1), components("0000"));
+    assert_eq!((0, 1), components("0_0_0"));
+    assert_eq!((3, 1), components("0_003"));
+    assert_eq!((3, 7), components("003/0_07"));
+    assert_eq!((7, 1000), components("0.007"));
+    assert_eq!((7, 1000), components("0.00_7"));
+    check_invalid("00/000", RatioErrorKind::ZeroDenominator);
+
+    let zeros = "0".repeat(10_000);
+    assert_eq!((3, 1), components(&(zeros.clone() + "3")));
+    assert_eq!((-3, 4), components(&format!("-{zeros}3/{zeros}4")));
+    assert_eq!((3, 1), components(&format!("{zeros}3.{zeros}e{zeros}")));
+    check_invalid(&format!("0.{zeros}3"), RatioErrorKind::Overflow);
+
+    let big = Ratio::<num_bigint::BigInt>::from_str_flex(&(zeros + "3")).unwrap();
+    assert_eq!(big.to_string(), "3");
+}
+
 #[test]
 fn test_fractions() {
     assert_eq!((3, 2), components("3/2"));
@@ -49,9 +81,34 @@ fn test_fractions() {
     assert_eq!((3, 2), components(" \n  +3/2"));
     assert_eq!((-3, 2), components("-3/2  "));
     assert_eq!((13, 2), components("    0013/002 \n  "));
+    assert_eq!((3, 2), components("\u{a0}3/2\u{3000}"));
     assert_eq!((41, 107), components("1_2_3/3_2_1"));
 }
 
+#[test]
+fn test_unicode_whitespace() {
+    for space in ['\u{a0}', '\u{2009}', '\u{202f}', '\u{3000}', '\u{2028}'] {
+        let padded = |s: &str| format!("{space}{s}{space}{space}");
+        assert_eq!((1, 2), components(&padded("1/2")), "space {:?}", space);
+        assert_eq!((-3, 2), components(&padded("-1.5")), "space {:?}", space);
+        assert_eq!((300, 1), components(&padded("3e2")), "space {:?}", space);
+        assert_eq!((1, 2), components(&format!("1{space}/{space}2")));
+
+        // Interior whitespace is still rejected.
+        for s in [
+            format!("1{space}000"),
+            format!("1.{space}5"),
+            format!("-{space}3"),
+            format!("3{space}e2"),
+        ] {
+            check_invalid(&s, RatioErrorKind::ParseError);
+        }
+    }
+    // Format characters are not whitespace.
+    check_invalid("\u{200b}1/2", RatioErrorKind::ParseError);
+    check_invalid("1/2\u{feff}", RatioErrorKind::ParseError);
+}
+
 #[test]
 fn test_decimals() {
     assert_eq!((16, 5), components(" 3.2 "));
@@ -64,6 +121,38 @@ fn test_decimals() {
     assert_eq!((101, 100), components("1.0_1"));
 }
 
+#[test]
+fn test_degenerate_decimals() {
+    // A digit on either side of the point is enough.
+    assert_eq!((0, 1), components("0."));
+    assert_eq!((0, 1), components(".0"));
+    assert_eq!((0, 1), components("-.0"));
+    assert_eq!((0, 1), components("-0."));
+    assert_eq!((0, 1), components("00."));
+    assert_eq!((0, 1), components(".0_0"));
+    assert_eq!((0, 1), components("0.e5"));
+    assert_eq!((0, 1), components(".0e5"));
+    assert_eq!((5, 1), components("5."));
+    assert_eq!((1, 2), components(".5"));
+
+    // No digits at all.
+    check_invalid(".", RatioErrorKind::ParseError);
+    check_invalid("-.", RatioErrorKind::ParseError);
+    check_invalid("+.", RatioErrorKind::ParseError);
+    check_invalid(" . ", RatioErrorKind::ParseError);
+    check_invalid(".e5", RatioErrorKind::ParseError);
+    check_invalid("-.e5", RatioErrorKind::ParseError);
+
+    // Misplaced points and separators.
+    check_invalid("._0", RatioErrorKind::ParseError);
+    check_invalid("0._", RatioErrorKind::ParseError);
+    check_invalid(". 5", RatioErrorKind::ParseError);
+    check_invalid(".5.", RatioErrorKind::ParseError);
+    check_invalid("..5", RatioErrorKind::ParseError);
+    check_invalid("./2", RatioErrorKind::ParseError);
+    check_invalid("0./2", RatioErrorKind::MixedFractionDecimal);
+}
+
 #[test]
 fn test_scientific() {
     assert_eq!((1, 3125), components("32.e-5"));
@@ -73,6 +162,67 @@ fn test_scientific() {
     assert_eq!((0, 1), components("-0.000e0"));
 }
 
+#[test]
+fn test_dangling_exponent() {
+    check_invalid("3e", RatioErrorKind::DanglingExponent);
+    check_invalid("3E", RatioErrorKind::DanglingExponent);
+    check_invalid("3e+", RatioErrorKind::DanglingExponent);
+    check_invalid("3e-", RatioErrorKind::DanglingExponent);
+    check_invalid(" -3E \n", RatioErrorKind::DanglingExponent);
+    check_invalid("3.e", RatioErrorKind::DanglingExponent);
+
+    // Decimals are reported the same way.
+    check_invalid("3.2e", RatioErrorKind::DanglingExponent);
+    check_invalid("3.2e+", RatioErrorKind::DanglingExponent);
+    check_invalid("3.2e-", RatioErrorKind::DanglingExponent);
+    check_invalid("-3.2E ", RatioErrorKind::DanglingExponent);
+    check_invalid(".5e", RatioErrorKind::DanglingExponent);
+    check_invalid("1_000.000_1e", RatioErrorKind::DanglingExponent);
+    check_invalid("3.2x", RatioErrorKind::ParseError);
+    check_invalid("3.2e+-1", RatioErrorKind::ParseError);
+    check_invalid(".e", RatioErrorKind::ParseError);
+
+    // Anything else after the marker is still a generic parse error.
+    check_invalid("3e+-1", RatioErrorKind::ParseError);
+    check_invalid("3e_1", RatioErrorKind::ParseError);
+    check_invalid("3e 1", RatioErrorKind::ParseError);
+    check_invalid("3ee", RatioErrorKind::ParseError);
+    check_invalid("3/4e", RatioErrorKind::ParseError);
+
+    // Without a mantissa there is no number to attach the exponent to.
+    check_invalid("e", RatioErrorKind::ParseError);
+    check_invalid("e5", RatioErrorKind::ParseError);
+    check_invalid("-e", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_trailing_zeros_with_exponent() {
+    // Trailing decimal zeros are stripped before the exponent is applied, and the
+    // exponent is combined with the decimal scale into a single power of ten.
+    assert_eq!((3, 2000), components("1.500e-3"));
+    assert_eq!((1500, 1), components("1.500e3"));
+    assert_eq!((15, 1), components("1.5000000000e1"));
+    assert_eq!((1, 1000), components("1.000_000_000_0e-3"));
+    assert_eq!((123_456_789, 100_000), components("1_234.567_890e0"));
+    assert_eq!((123_456_789, 100), components("1_234.567_890e3"));
+    assert_eq!((123_456_789, 1_000_000), components("1_234.567_890e-1"));
+
+    // Positive exponents cancel against the decimal scale instead of overflowing.
+    assert_eq!((2147483647, 1), components("2.147483647e9"));
+    assert_eq!((2147483647, 10), components("2.147483647e8"));
+    assert_eq!((1, 1), components("0.0000000001e10"));
+    check_invalid("2.147483648e9", RatioErrorKind::Overflow);
+
+    // Zero stays zero regardless of the exponent.
+    assert_eq!((0, 1), components("0e100"));
+    assert_eq!((0, 1), components("0.000e-100"));
+    assert_eq!((0, 1), components("-.0e2147483647"));
+
+    // Significant digits still have to fit.
+    check_invalid("1.5e-9", RatioErrorKind::Overflow);
+    check_invalid("1.5e10", RatioErrorKind::Overflow);
+}
+
 #[test]
 fn test_underscores() {
     assert_eq!((123, 1), components("1_2_3"));
@@ -80,16 +230,51 @@ fn test_underscores() {
     assert_eq!((6283, 2000), components("3.14_15"));
 }
 
+#[test]
+fn test_grouped_integer_with_exponent() {
+    assert_eq!((12000, 1), components("1_2e3"));
+    assert_eq!((12000, 1), components("1_2E+3"));
+    assert_eq!((-12000, 1), components("-1_2e3"));
+    assert_eq!((1_000_000, 1), components("1_000e3"));
+
+    // Trailing zeros of the grouped digits cancel against a negative exponent.
+    assert_eq!((1, 1), components("1_000e-3"));
+    assert_eq!((1, 1), components("1_000_000e-6"));
+    assert_eq!((1, 100), components("1_000e-5"));
+    assert_eq!((-3, 2), components("-1_5e-1"));
+    assert_eq!((41, 1000), components("4_1e-3"));
+
+    // The exponent may be grouped as well.
+    assert_eq!((20_000_000, 1), components("2e0_7"));
+    assert_eq!((1, 1), components("1_000_000_000_0e-1_0"));
+    check_invalid("1_0e1_0", RatioErrorKind::Overflow);
+    let err = Rational32::from_str_flex("1_0e1_0").unwrap_err();
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Numerator));
+    assert_eq!(
+        Rational64::from_str_flex("1_0e1_0").unwrap(),
+        Ratio::from_integer(100_000_000_000)
+    );
+
+    // Separators only go between digits.
+    check_invalid("1__2e3", RatioErrorKind::ParseError);
+    check_invalid("1_2_e3", RatioErrorKind::ParseError);
+    check_invalid("1_2e_3", RatioErrorKind::ParseError);
+    check_invalid("1_2e3_", RatioErrorKind::ParseError);
+    check_invalid("_12e3", RatioErrorKind::ParseError);
+}
+
 #[test]
 fn test_overflow() {
     // Integer overflow: exceeds i32::MAX (2147483647)
     check_invalid("2147483648", RatioErrorKind::Overflow);
     check_invalid("99999999999", RatioErrorKind::Overflow);
-    check_invalid("-2147483648", RatioErrorKind::Overflow);
+    check_invalid("-2147483649", RatioErrorKind::Overflow);
+    // i32::MIN itself fits
+    assert_eq!((i32::MIN, 1), components("-2147483648"));
 
     // Fraction overflow: numerator exceeds i32::MAX
     check_invalid("2147483648/1", RatioErrorKind::Overflow);
-    check_invalid("-2147483648/1", RatioErrorKind::Overflow);
+    check_invalid("-2147483649/1", RatioErrorKind::Overflow);
 
     // Fraction overflow: denominator exceeds i32::MAX
     check_invalid("1/2147483648", RatioErrorKind::Overflow);
@@ -122,8 +307,8 @@ fn test_invalid() {
     check_invalid("+ 3/2", RatioErrorKind::ParseError);
     check_invalid("- 3/2", RatioErrorKind::ParseError);
     check_invalid("3a2", RatioErrorKind::ParseError);
-    check_invalid("3/7.2", RatioErrorKind::ParseError);
-    check_invalid("3.2/7", RatioErrorKind::ParseError);
+    check_invalid("3/7.2", RatioErrorKind::MixedFractionDecimal);
+    check_invalid("3.2/7", RatioErrorKind::MixedFractionDecimal);
     check_invalid("3 .2", RatioErrorKind::ParseError);
     check_invalid("3. 2", RatioErrorKind::ParseError);
     check_invalid("3.2 e1", RatioErrorKind::ParseError);
@@ -171,6 +356,32 @@ fn test_invalid() {
     check_invalid("789e2_dd", RatioErrorKind::ParseError);
 }
 
+#[test]
+fn test_non_ascii_digits() {
+    let position = |position| RatioErrorKind::NonAsciiDigit { position };
+
+    check_invalid("١", position(0));
+    check_invalid("3/٢", position(2));
+    check_invalid("1٢3", position(1));
+    check_invalid(" -1.5e٣", position(6));
+    check_invalid("３", position(0));
+    check_invalid("x١", position(1));
+    check_invalid("12٣٤", position(2));
+
+    // Other numeric characters are not decimal digits.
+    check_invalid("³", RatioErrorKind::ParseError);
+    check_invalid("¼", RatioErrorKind::ParseError);
+    check_invalid("Ⅻ", RatioErrorKind::ParseError);
+
+    // Positions refer to the original input, even with a multi-byte separator.
+    let parser = RationalParser::new().grouping_separator(Some('’'));
+    assert_eq!(parser.parse::<i32>("1’000").unwrap(), Ratio::new(1000, 1));
+    assert_eq!(
+        *parser.parse::<i32>("1’00٠").unwrap_err().kind(),
+        position(6)
+    );
+}
+
 #[test]
 fn test_backtracking() {
     // Catastrophic backtracking test
@@ -205,3 +416,3050 @@ fn test_aliases() {
         Rational8::new(127, 1)
     );
 }
+
+#[test]
+fn test_widest() {
+    assert_eq!(
+        parse_flex_widest("99999999999999999999/3").unwrap(),
+        Ratio::new(33333333333333333333, 1)
+    );
+    assert_eq!(
+        parse_flex_widest("1e-30").unwrap(),
+        Ratio::new(1, 10i128.pow(30))
+    );
+    assert_eq!(
+        parse_flex_widest(&i128::MAX.to_string()).unwrap(),
+        Ratio::from_integer(i128::MAX)
+    );
+    assert_eq!(
+        *parse_flex_widest("1e39").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+
+    // Values beyond i128 still parse exactly into BigInt.
+    type BigRational = Ratio<num_bigint::BigInt>;
+    let big = BigRational::from_str_flex("1e40").unwrap();
+    assert_eq!(big.to_string(), format!("1{}", "0".repeat(40)));
+}
+
+#[test]
+fn test_require_sign() {
+    let parser = RationalParser::new().require_sign(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    assert_eq!(parse("+3/2").unwrap(), Ratio::new(3, 2));
+    assert_eq!(parse("-3/2").unwrap(), Ratio::new(-3, 2));
+    assert_eq!(parse(" -1.5e1 ").unwrap(), Ratio::new(-15, 1));
+    assert_eq!(parse("+.5").unwrap(), Ratio::new(1, 2));
+
+    for s in ["3/2", "0", " 1.5", ".5", "1e3"] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::MissingSign,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(*parse("+").unwrap_err().kind(), RatioErrorKind::ParseError);
+    assert_eq!(
+        *parse("+ 3").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+
+    // The default parser keeps the sign optional.
+    assert_eq!(
+        RationalParser::new().parse::<i32>("3/2").unwrap(),
+        Ratio::new(3, 2)
+    );
+}
+
+#[test]
+fn test_percent() {
+    let parser = RationalParser::new().allow_percent(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    assert_eq!(parse("50%").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse(" -12.5 % ").unwrap(), Ratio::new(-1, 8));
+    assert_eq!(parse("1/3%").unwrap(), Ratio::new(1, 300));
+    assert_eq!(parse("1e2%").unwrap(), Ratio::new(1, 1));
+    assert_eq!(parse("50").unwrap(), Ratio::new(50, 1));
+    assert_eq!(*parse("%").unwrap_err().kind(), RatioErrorKind::ParseError);
+    assert_eq!(
+        *parse("5%%").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+    assert_eq!(*parse("%5").unwrap_err().kind(), RatioErrorKind::ParseError);
+
+    // The percentage is reduced before the denominator is scaled.
+    type Rational8 = Ratio<i8>;
+    assert_eq!(
+        RationalParser::new()
+            .allow_percent(true)
+            .parse::<i8>("50%")
+            .unwrap(),
+        Rational8::new(1, 2)
+    );
+    assert_eq!(
+        *RationalParser::new()
+            .allow_percent(true)
+            .parse::<i8>("1/2%")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+
+    // Percent signs are rejected by default.
+    check_invalid("50%", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_percent_of() {
+    let base = Ratio::new(300, 1);
+    assert_eq!(from_percent_of("50%", base).unwrap(), Ratio::new(150, 1));
+    assert_eq!(from_percent_of("150 %", base).unwrap(), Ratio::new(450, 1));
+    assert_eq!(from_percent_of("1/3%", base).unwrap(), Ratio::new(1, 1));
+    assert_eq!(
+        *from_percent_of("50", base).unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+    assert_eq!(
+        *from_percent_of("200%", Ratio::<i8>::new(100, 1))
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_sign_invariant() {
+    let parser = RationalParser::new()
+        .allow_signed_denominator(true)
+        .allow_percent(true)
+        .allow_fraction_exponent(true);
+    let cases = [
+        ("-3/4", -1),
+        ("3/-4", -1),
+        ("-3/-4", 1),
+        ("+3/+4", 1),
+        ("-1.25", -1),
+        ("-.5", -1),
+        ("-47e-2", -1),
+        ("-5e3", -1),
+        ("-2/3e-1", -1),
+        ("-50%", -1),
+        ("3/-4%", -1),
+        ("0", 0),
+        ("-0", 0),
+        ("0/-7", 0),
+        ("-0.0e5", 0),
+        ("7/2", 1),
+        ("1e-9", 1),
+    ];
+    for (s, expected) in cases {
+        let r = parser.parse::<i32>(s).unwrap();
+        assert!(r.denom().is_positive(), "input {:?}", s);
+        assert_eq!(r.numer().signum(), expected, "input {:?}", s);
+        assert_eq!(sign(&r), expected, "input {:?}", s);
+    }
+
+    let r = parser.parse::<i8>("-128").unwrap();
+    assert_eq!((sign(&r), *r.denom()), (-1, 1));
+    let r = RationalParser::new()
+        .preserve_trailing_zeros(true)
+        .parse::<i32>("-1.50")
+        .unwrap();
+    assert_eq!((sign(&r), *r.denom()), (-1, 100));
+    assert_eq!(sign(&Ratio::new_raw(3, -4)), -1);
+}
+
+#[test]
+fn test_percent_change() {
+    let change = |s: &str| from_percent_change::<i32>(s);
+    assert_eq!(change("+5%").unwrap(), Ratio::new(21, 20));
+    assert_eq!(change("5%").unwrap(), Ratio::new(21, 20));
+    assert_eq!(change("-10%").unwrap(), Ratio::new(9, 10));
+    assert_eq!(change(" +12.5 % ").unwrap(), Ratio::new(9, 8));
+    assert_eq!(change("0%").unwrap(), Ratio::from_integer(1));
+    assert_eq!(change("-100%").unwrap(), Ratio::from_integer(0));
+    assert_eq!(change("-150%").unwrap(), Ratio::new(-1, 2));
+    assert_eq!(change("200%").unwrap(), Ratio::from_integer(3));
+    assert_eq!(change("1/3%").unwrap(), Ratio::new(301, 300));
+
+    for s in ["5", "%", "+%", "5%%", "x%"] {
+        assert_eq!(
+            *change(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *change("1/0%").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        *from_percent_change::<i8>("0.5%").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        *from_percent_change::<i8>("12700%").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_classify_flex() {
+    let class = |s: &str| classify_flex::<i32>(s).map(|(_, c)| c).unwrap();
+    for s in ["0", "-42", "+7", " 1_000 ", "007"] {
+        assert_eq!(class(s), FormatClass::Integer, "input {:?}", s);
+    }
+    for s in ["1/2", "4/2", "-3 / 4", "1_0/2_0"] {
+        assert_eq!(class(s), FormatClass::Fraction, "input {:?}", s);
+    }
+    for s in ["3.14", "2.0", "-.5", "5.", "1_000.000_1"] {
+        assert_eq!(class(s), FormatClass::Decimal, "input {:?}", s);
+    }
+    for s in ["1e5", "-2.5E-3", "3.e2", ".5e+1", "1e0"] {
+        assert_eq!(class(s), FormatClass::Scientific, "input {:?}", s);
+    }
+
+    // The value is the same as from `from_str_flex`.
+    for s in ["4/2", "-2.50e1", "0.125"] {
+        let (value, _) = classify_flex::<i32>(s).unwrap();
+        assert_eq!(
+            value,
+            Rational32::from_str_flex(s).unwrap(),
+            "input {:?}",
+            s
+        );
+    }
+    for s in ["1/0", "x", "3/", "1e"] {
+        assert_eq!(
+            classify_flex::<i32>(s).unwrap_err(),
+            Rational32::from_str_flex(s).unwrap_err(),
+            "input {:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_parse_number() {
+    let number = |s: &str| parse_number::<i32>(s).unwrap();
+    for (s, expected) in [
+        ("6/2", 3),
+        ("3e2", 300),
+        ("-2.50e1", -25),
+        ("0", 0),
+        ("-0.0", 0),
+    ] {
+        assert_eq!(number(s), ParsedNumber::Integer(expected), "input {:?}", s);
+    }
+    for (s, n, d) in [
+        ("3/2", 3, 2),
+        ("0.5", 1, 2),
+        ("-1e-3", -1, 1000),
+        ("6/4", 3, 2),
+    ] {
+        assert_eq!(
+            number(s),
+            ParsedNumber::Rational(Ratio::new(n, d)),
+            "input {:?}",
+            s
+        );
+    }
+    assert_ne!(number("3"), ParsedNumber::Rational(Ratio::from_integer(3)));
+    assert_eq!(number("3").into_ratio(), Ratio::from_integer(3));
+    assert_eq!(number("3/4").into_ratio(), Ratio::new(3, 4));
+    assert_eq!(
+        parse_number::<i8>("-128").unwrap(),
+        ParsedNumber::Integer(-128)
+    );
+    assert_eq!(
+        parse_number::<i32>("1/0").unwrap_err(),
+        Rational32::from_str_flex("1/0").unwrap_err()
+    );
+}
+
+#[test]
+fn test_allowed_denoms() {
+    let notes = [2, 4, 8, 16];
+    let check = |s: &str| from_str_flex_with_allowed_denoms::<i32>(s, &notes);
+    assert_eq!(check("3/4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(check("6/8").unwrap(), Ratio::new(3, 4));
+    assert_eq!(check("0.375").unwrap(), Ratio::new(3, 8));
+    assert_eq!(check("-5/16").unwrap(), Ratio::new(-5, 16));
+    assert_eq!(check("2/4").unwrap(), Ratio::new(1, 2));
+
+    for s in ["1/3", "2", "0", "1/32", "0.1"] {
+        assert_eq!(
+            *check(s).unwrap_err().kind(),
+            RatioErrorKind::DenominatorNotAllowed,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        from_str_flex_with_allowed_denoms::<i32>("4/2", &[1, 2]).unwrap(),
+        Ratio::from_integer(2)
+    );
+    assert_eq!(
+        *from_str_flex_with_allowed_denoms::<i32>("1/2", &[])
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::DenominatorNotAllowed
+    );
+
+    // Parse errors take precedence.
+    assert_eq!(
+        *check("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(*check("x").unwrap_err().kind(), RatioErrorKind::ParseError);
+    assert_eq!(
+        RatioErrorKind::DenominatorNotAllowed.to_string(),
+        "denominator not allowed"
+    );
+}
+
+#[test]
+fn test_denominator_factors() {
+    let factors = |s: &str| denominator_factors(&Rational64::from_str_flex(s).unwrap());
+    assert_eq!(factors("1/4"), [(2, 2)]);
+    assert_eq!(factors("1/6"), [(2, 1), (3, 1)]);
+    assert_eq!(factors("1/7"), [(7, 1)]);
+    assert_eq!(factors("-5/12"), [(2, 2), (3, 1)]);
+    assert_eq!(factors("0.001"), [(2, 3), (5, 3)]);
+    assert_eq!(factors("1/9"), [(3, 2)]);
+    assert_eq!(factors("1/49"), [(7, 2)]);
+    assert_eq!(factors("1/1001"), [(7, 1), (11, 1), (13, 1)]);
+    assert_eq!(factors("1/2147483647"), [(2147483647, 1)]);
+    assert_eq!(factors("1/4294967294"), [(2, 1), (2147483647, 1)]);
+    assert_eq!(factors("6/3"), []);
+    assert_eq!(factors("0"), []);
+
+    // The factors tell whether the decimal expansion terminates.
+    for s in ["1/3", "7/40", "1/625", "5/14"] {
+        let r = Rational64::from_str_flex(s).unwrap();
+        let terminating = denominator_factors(&r)
+            .iter()
+            .all(|&(p, _)| p == 2 || p == 5);
+        assert_eq!(
+            terminating,
+            RationalParser::new()
+                .require_terminating_decimal(true)
+                .parse::<i64>(s)
+                .is_ok(),
+            "input {:?}",
+            s
+        );
+    }
+
+    // Large factors near the limit of the type do not overflow.
+    assert_eq!(denominator_factors(&Ratio::<i8>::new(1, 127)), [(127, 1)]);
+    assert_eq!(denominator_factors(&Ratio::<i8>::new(1, 121)), [(11, 2)]);
+    assert_eq!(denominator_factors(&Ratio::<i8>::new(1, 125)), [(5, 3)]);
+}
+
+#[test]
+fn test_from_str_flex_default_denom() {
+    let parse = |s: &str, d: i32| from_str_flex_default_denom(s, d).map_err(|e| *e.kind());
+
+    assert_eq!(parse("3", 16), Ok(Ratio::new(3, 16)));
+    assert_eq!(parse(" -3 ", 16), Ok(Ratio::new(-3, 16)));
+    assert_eq!(parse("4", 16), Ok(Ratio::new(1, 4)));
+    assert_eq!(parse("1_6", 16), Ok(Ratio::from_integer(1)));
+    assert_eq!(parse("0", 16), Ok(Ratio::from_integer(0)));
+    assert_eq!(parse("3", 1), Ok(Ratio::from_integer(3)));
+    assert_eq!(parse("3", -4), Ok(Ratio::new(-3, 4)));
+
+    // Explicit fractions, decimals and exponents ignore the default.
+    assert_eq!(parse("5/8", 16), Ok(Ratio::new(5, 8)));
+    assert_eq!(parse("6/1", 16), Ok(Ratio::from_integer(6)));
+    assert_eq!(parse("3.0", 16), Ok(Ratio::from_integer(3)));
+    assert_eq!(parse("3.", 16), Ok(Ratio::from_integer(3)));
+    assert_eq!(parse("3e0", 16), Ok(Ratio::from_integer(3)));
+    assert_eq!(parse("-1.5e1", 16), Ok(Ratio::from_integer(-15)));
+
+    assert_eq!(parse("3", 0), Err(RatioErrorKind::ZeroDenominator));
+    assert_eq!(parse("5/8", 0), Err(RatioErrorKind::ZeroDenominator));
+    assert_eq!(parse("1/0", 16), Err(RatioErrorKind::ZeroDenominator));
+    assert_eq!(parse("x", 16), Err(RatioErrorKind::ParseError));
+    assert_eq!(parse("2147483648", 16), Err(RatioErrorKind::Overflow));
+
+    assert_eq!(
+        from_str_flex_default_denom("-128", -1i8).map_err(|e| *e.kind()),
+        Err(RatioErrorKind::Overflow)
+    );
+    assert_eq!(
+        from_str_flex_default_denom("-128", 2i8).unwrap(),
+        Ratio::from_integer(-64)
+    );
+}
+
+#[test]
+fn test_from_str_flex_unsigned() {
+    let parse = |s: &str| from_str_flex_unsigned::<u32>(s).map_err(|e| *e.kind());
+
+    assert_eq!(parse("3/4"), Ok(Ratio::new(3, 4)));
+    assert_eq!(parse("+1.25"), Ok(Ratio::new(5, 4)));
+    assert_eq!(parse("4294967295"), Ok(Ratio::from_integer(u32::MAX)));
+    assert_eq!(parse("1/4294967295"), Ok(Ratio::new(1, u32::MAX)));
+    assert_eq!(parse("-0"), Ok(Ratio::from_integer(0)));
+    assert_eq!(parse("-0/5"), Ok(Ratio::from_integer(0)));
+
+    for s in ["-3/4", "-1", "-0.5", "-1e-3", "-4294967296"] {
+        assert_eq!(
+            parse(s),
+            Err(RatioErrorKind::UnsignedNegative),
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(parse("4294967296"), Err(RatioErrorKind::Overflow));
+    assert_eq!(parse("1/4294967296"), Err(RatioErrorKind::Overflow));
+    assert_eq!(parse("1/0"), Err(RatioErrorKind::ZeroDenominator));
+    assert_eq!(parse("x"), Err(RatioErrorKind::ParseError));
+
+    // Values are parsed through `i128`.
+    assert_eq!(
+        from_str_flex_unsigned::<u128>("170141183460469231731687303715884105727").unwrap(),
+        Ratio::from_integer(i128::MAX as u128)
+    );
+    assert_eq!(
+        *from_str_flex_unsigned::<u128>("170141183460469231731687303715884105728")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        from_str_flex_unsigned::<u8>("255/3").unwrap(),
+        Ratio::from_integer(85)
+    );
+    assert_eq!(
+        RatioErrorKind::UnsignedNegative.to_string(),
+        "negative value for unsigned type"
+    );
+}
+
+#[test]
+fn test_snap_to_nice() {
+    let snap =
+        |s: &str, denoms: &[i32]| snap_to_nice(&Rational32::from_str_flex(s).unwrap(), denoms);
+    let nice = [2, 3, 4, 8];
+
+    assert_eq!(snap("0.33", &nice), Some(Ratio::new(1, 3)));
+    assert_eq!(snap("0.66", &nice), Some(Ratio::new(2, 3)));
+    assert_eq!(snap("0.13", &nice), Some(Ratio::new(1, 8)));
+    assert_eq!(snap("0.74", &nice), Some(Ratio::new(3, 4)));
+    assert_eq!(snap("2.49", &nice), Some(Ratio::new(5, 2)));
+    assert_eq!(snap("-0.33", &nice), Some(Ratio::new(-1, 3)));
+    assert_eq!(snap("0.01", &nice), Some(Ratio::from_integer(0)));
+    assert_eq!(snap("3", &nice), Some(Ratio::from_integer(3)));
+    assert_eq!(snap("0.49", &[4]), Some(Ratio::new(1, 2)));
+
+    // Ties go to the smallest denominator: 5/12 is 1/12 from both 1/3 and 1/2.
+    assert_eq!(snap("5/12", &[2, 3]), Some(Ratio::new(1, 2)));
+    assert_eq!(snap("5/12", &[3, 2]), Some(Ratio::new(1, 2)));
+    assert_eq!(snap("-5/12", &[3, 2]), Some(Ratio::new(-1, 2)));
+    // 3/8 is as close to 1/4 as to 1/2; both come from the denominator 4,
+    // which rounds half away from zero.
+    assert_eq!(snap("3/8", &[4]), Some(Ratio::new(1, 2)));
+    assert_eq!(snap("-3/8", &[4]), Some(Ratio::new(-1, 2)));
+    // An exact match beats any other denominator.
+    assert_eq!(snap("3/8", &[2, 4, 8]), Some(Ratio::new(3, 8)));
+
+    assert_eq!(snap("0.5", &[]), None);
+
+    // Candidates that overflow are skipped.
+    let big = Ratio::<i8>::from_integer(100);
+    assert_eq!(snap_to_nice(&big, &[2, 1]), Some(Ratio::from_integer(100)));
+    assert_eq!(snap_to_nice(&big, &[2, 4]), None);
+    let min = Ratio::<i8>::new_raw(i8::MIN, 1);
+    assert_eq!(snap_to_nice(&min, &[1]), Some(min));
+}
+
+#[test]
+#[should_panic]
+fn test_snap_to_nice_zero_denominator() {
+    snap_to_nice(&Ratio::new(1, 3), &[2, 0]);
+}
+
+#[test]
+fn test_from_str_flex_map_err() {
+    #[derive(Debug, PartialEq)]
+    enum AppError {
+        BadNumber(RatioErrorKind),
+        BadField { input: String, kind: RatioErrorKind },
+    }
+
+    let ok = Rational32::from_str_flex_map_err("-1.5", |e| AppError::BadNumber(*e.kind()));
+    assert_eq!(ok, Ok(Ratio::new(-3, 2)));
+    let err = Rational32::from_str_flex_map_err("1/0", |e| AppError::BadNumber(*e.kind()));
+    assert_eq!(
+        err,
+        Err(AppError::BadNumber(RatioErrorKind::ZeroDenominator))
+    );
+
+    let with_input = |s: &str| {
+        Ratio::<i8>::from_str_flex_map_err_with_input(s, |e, input| AppError::BadField {
+            input: input.to_owned(),
+            kind: *e.kind(),
+        })
+    };
+    assert_eq!(with_input(" 1/4 "), Ok(Ratio::new(1, 4)));
+    assert_eq!(
+        with_input(" 300 "),
+        Err(AppError::BadField {
+            input: " 300 ".to_owned(),
+            kind: RatioErrorKind::Overflow
+        })
+    );
+    assert_eq!(
+        with_input("abc"),
+        Err(AppError::BadField {
+            input: "abc".to_owned(),
+            kind: RatioErrorKind::ParseError
+        })
+    );
+
+    // The closure is not called on success.
+    let ok: Result<Rational32, ()> =
+        Rational32::from_str_flex_map_err_with_input("2", |_, _| panic!("called on success"));
+    assert_eq!(ok, Ok(Ratio::from_integer(2)));
+}
+
+#[test]
+fn test_compare_flex() {
+    use std::cmp::Ordering;
+
+    let half = Ratio::new(1, 2);
+    assert_eq!(
+        compare_flex::<i32>("0.75", &half).unwrap(),
+        Ordering::Greater
+    );
+    assert_eq!(compare_flex::<i32>("1/3", &half).unwrap(), Ordering::Less);
+    assert_eq!(compare_flex::<i32>("5e-1", &half).unwrap(), Ordering::Equal);
+    assert!(compare_flex::<i32>("-2/-4", &half).is_err());
+    assert_eq!(
+        compare_flex::<i32>("-0.5", &Ratio::new(-1, 2)).unwrap(),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_flex::<i64>("2147483648", &Ratio::from_integer(i64::from(i32::MAX))).unwrap(),
+        Ordering::Greater
+    );
+    assert_eq!(
+        *compare_flex::<i32>("1/0", &half).unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+
+    let kept: Vec<_> = ["0.4", "0.6", "3/4", "1/2"]
+        .into_iter()
+        .filter(|s| compare_flex(s, &half) == Ok(Ordering::Greater))
+        .collect();
+    assert_eq!(kept, ["0.6", "3/4"]);
+}
+
+#[test]
+fn test_reciprocal() {
+    let recip = |s: &str| from_str_flex_reciprocal::<i32>(s);
+    assert_eq!(recip("0.25").unwrap(), Ratio::from_integer(4));
+    assert_eq!(recip("3/4").unwrap(), Ratio::new(4, 3));
+    assert_eq!(recip("-2").unwrap(), Ratio::new(-1, 2));
+    assert_eq!(recip("-6/4").unwrap(), Ratio::new(-2, 3));
+    assert_eq!(recip("1e-3").unwrap(), Ratio::from_integer(1000));
+    assert_eq!(*recip("-3/4").unwrap().denom(), 3);
+
+    for s in ["0", "-0.0", "0/5"] {
+        assert_eq!(
+            *recip(s).unwrap_err().kind(),
+            RatioErrorKind::ZeroDenominator,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *recip("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(*recip("x").unwrap_err().kind(), RatioErrorKind::ParseError);
+
+    assert_eq!(
+        from_str_flex_reciprocal::<i8>("127").unwrap(),
+        Ratio::new(1, 127)
+    );
+    assert_eq!(
+        *from_str_flex_reciprocal::<i8>("-128").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_fixed_point() {
+    let fixed = |s: &str, scale: u32| from_fixed_point::<i32>(s, scale);
+    assert_eq!(fixed("12345", 3).unwrap(), Ratio::new(2469, 200));
+    assert_eq!(fixed("12345", 0).unwrap(), Ratio::from_integer(12345));
+    assert_eq!(fixed("-250", 2).unwrap(), Ratio::new(-5, 2));
+    assert_eq!(fixed(" +1_000 ", 1).unwrap(), Ratio::from_integer(100));
+    assert_eq!(fixed("7", 9).unwrap(), Ratio::new(7, 1_000_000_000));
+    assert_eq!(fixed("1000", 12).unwrap(), Ratio::new(1, 1_000_000_000));
+    assert_eq!(fixed("-0", 5).unwrap(), Ratio::from_integer(0));
+
+    for s in ["12.345", "1/2", "1e3", "", "-", "12 34", "abc"] {
+        assert_eq!(
+            *fixed(s, 3).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *fixed("7", 10).unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        *fixed("7", u32::MAX).unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+
+    // Positions refer to the input as given.
+    for (s, position) in [("  ٣", 2), (" 12٣ ", 3), ("٣", 0)] {
+        assert_eq!(
+            *fixed(s, 2).unwrap_err().kind(),
+            RatioErrorKind::NonAsciiDigit { position },
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *from_percent_change::<i32>(" +1٣%").unwrap_err().kind(),
+        RatioErrorKind::NonAsciiDigit { position: 3 }
+    );
+    assert_eq!(
+        *from_percent_of("  ٣ %", Ratio::<i32>::from_integer(5))
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::NonAsciiDigit { position: 2 }
+    );
+}
+
+#[test]
+fn test_space_pair() {
+    let pair = |s: &str| from_space_pair::<i32>(s);
+    assert_eq!(pair("3 4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(pair("  6\t8\n").unwrap(), Ratio::new(3, 4));
+    assert_eq!(pair("-3 4").unwrap(), Ratio::new(-3, 4));
+    assert_eq!(pair("3 -4").unwrap(), Ratio::new(-3, 4));
+    assert_eq!(pair("-3 -4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(pair("+1_000 2_000").unwrap(), Ratio::new(1, 2));
+    assert_eq!(pair("0 5").unwrap(), Ratio::from_integer(0));
+
+    for s in [
+        "", "3", "3 4 5", "3/4", "3 / 4", "3.5 2", "3 2.5", "1e2 3", "x 4", "--3 4", "3_ 4",
+        "- 3 4",
+    ] {
+        assert_eq!(
+            *pair(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *pair("3 0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        *pair("3 -0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+
+    let err = pair("3 4000000000").unwrap_err();
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Denominator));
+    let err = pair("4000000000 3").unwrap_err();
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Numerator));
+    assert_eq!(
+        from_space_pair::<i8>("-128 1").unwrap(),
+        Ratio::from_integer(-128)
+    );
+
+    assert_eq!(
+        *pair(" 3  4٣").unwrap_err().kind(),
+        RatioErrorKind::NonAsciiDigit { position: 5 }
+    );
+}
+
+#[test]
+fn test_widen() {
+    let r = Rational32::from_str_flex("-2147483647/2147483646").unwrap();
+    let wide: Rational64 = widen(r).unwrap();
+    assert_eq!(wide, Rational64::new(-2147483647, 2147483646));
+    assert_eq!(widen::<i64, i32>(wide), Some(r));
+
+    let r = Rational64::from_str_flex("1e-12").unwrap();
+    assert_eq!(widen::<i64, i32>(r), None);
+    assert_eq!(widen::<i64, i128>(r), Some(Ratio::new(1, 10i128.pow(12))));
+
+    let big = Ratio::<num_bigint::BigInt>::from_str_flex("-3/4").unwrap();
+    assert_eq!(widen::<_, i8>(big), Some(Ratio::new(-3, 4)));
+}
+
+#[test]
+fn test_validate() {
+    for s in ["314", "-35/4", "3.1415", "-47e-2", "1_000/1", "6/4"] {
+        assert_eq!(validate_flex::<i32>(s), Ok(()), "input {:?}", s);
+    }
+    for s in ["invalid", "3/", "1__2", "1/0", "2147483648", "1e-10"] {
+        assert_eq!(
+            validate_flex::<i32>(s).unwrap_err(),
+            Rational32::from_str_flex(s).unwrap_err(),
+            "input {:?}",
+            s
+        );
+    }
+
+    assert_eq!(validate_all::<i32>(&[]), Ok(()));
+    assert_eq!(validate_all::<i32>(&["1/2", " 3 ", ".5e1"]), Ok(()));
+    let (index, err) = validate_all::<i8>(&["1/2", "x", "1000"]).unwrap_err();
+    assert_eq!(index, 1);
+    assert_eq!(*err.kind(), RatioErrorKind::ParseError);
+    let (index, err) = validate_all::<i8>(&["1/2", "1000", "x"]).unwrap_err();
+    assert_eq!(index, 1);
+    assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+}
+
+#[test]
+fn test_grouping_separator() {
+    let commas = RationalParser::new().grouping_separator(Some(','));
+    let parse = |s: &str| commas.parse::<i32>(s);
+
+    assert_eq!(parse("1,000,000").unwrap(), Ratio::new(1_000_000, 1));
+    assert_eq!(
+        parse("-1,000.000,5").unwrap(),
+        Ratio::new(-2_000_001, 2_000)
+    );
+    assert_eq!(parse("1,000/2,000").unwrap(), Ratio::new(1, 2));
+    assert_eq!(
+        parse("1e1,0").unwrap_err().kind(),
+        &RatioErrorKind::Overflow
+    );
+    for s in [
+        "1_000", ",1", "1,", "1,,000", "1/,2", "1.,5", "1,.5", "1,e3",
+    ] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+
+    let apostrophes = RationalParser::new().grouping_separator(Some('\''));
+    assert_eq!(
+        apostrophes.parse::<i32>("1'234.5").unwrap(),
+        Ratio::new(2469, 2)
+    );
+
+    let none = RationalParser::new().grouping_separator(None);
+    assert_eq!(none.parse::<i32>("1000").unwrap(), Ratio::new(1000, 1));
+    assert_eq!(
+        *none.parse::<i32>("1_000").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+
+    // Underscores remain the default.
+    assert_eq!(
+        RationalParser::new().parse::<i32>("1_000").unwrap(),
+        Ratio::new(1000, 1)
+    );
+    check_invalid("1,000", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_grouping_separators() {
+    let both = RationalParser::new().grouping_separators(&[',', '_']);
+    let parse = |s: &str| both.parse::<i32>(s);
+
+    assert_eq!(parse("1,000").unwrap(), Ratio::new(1000, 1));
+    assert_eq!(parse("1_000").unwrap(), Ratio::new(1000, 1));
+    assert_eq!(parse("1000").unwrap(), Ratio::new(1000, 1));
+    assert_eq!(
+        parse("-1,000.000,5").unwrap(),
+        Ratio::new(-2_000_001, 2_000)
+    );
+    assert_eq!(parse("1_000/2_000").unwrap(), Ratio::new(1, 2));
+    for s in [
+        "1,000_000",
+        "1,000/2_000",
+        "1_000.5,5",
+        "1,_000",
+        ",1",
+        "1_",
+        "1,,000",
+    ] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+
+    let none = RationalParser::new().grouping_separators(&[]);
+    assert_eq!(
+        *none.parse::<i32>("1_000").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+    let quotes = RationalParser::new().grouping_separators(&['\'', '’']);
+    assert_eq!(quotes.parse::<i32>("1’234").unwrap(), Ratio::new(1234, 1));
+    assert_eq!(quotes.parse::<i32>("1'234").unwrap(), Ratio::new(1234, 1));
+    assert_eq!(
+        *quotes.parse::<i32>("1_234").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid grouping separator 'e'")]
+fn test_grouping_separators_conflict() {
+    let _ = RationalParser::new().grouping_separators(&[',', 'e']);
+}
+
+#[test]
+#[should_panic(expected = "invalid grouping separator")]
+fn test_grouping_separator_conflict() {
+    let _ = RationalParser::new().grouping_separator(Some('.'));
+}
+
+#[test]
+fn test_require_proper_fraction() {
+    let parser = RationalParser::new().require_proper_fraction(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    assert_eq!(parse("1/2").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse("-0.5").unwrap(), Ratio::new(-1, 2));
+    assert_eq!(parse("0").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parse("999e-3").unwrap(), Ratio::new(999, 1000));
+    assert_eq!(
+        parse("-2147483646/2147483647").unwrap().numer(),
+        &-2147483646
+    );
+
+    for s in ["3/2", "5", "2/2", "-1", "-3/2", "1.0", "1e0", "2147483647"] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::ImproperFraction,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(*parse("x").unwrap_err().kind(), RatioErrorKind::ParseError);
+    assert_eq!(
+        *parse("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+
+    // Applies to the value after percent scaling.
+    let percent = RationalParser::new()
+        .require_proper_fraction(true)
+        .allow_percent(true);
+    assert_eq!(percent.parse::<i32>("50%").unwrap(), Ratio::new(1, 2));
+    assert_eq!(
+        *percent.parse::<i32>("100%").unwrap_err().kind(),
+        RatioErrorKind::ImproperFraction
+    );
+}
+
+#[test]
+fn test_clamped01() {
+    let clamp = |s: &str| from_str_flex_clamped01::<i32>(s, RangePolicy::Clamp).unwrap();
+    let strict = |s: &str| from_str_flex_clamped01::<i32>(s, RangePolicy::Error);
+
+    for (s, expected) in [
+        ("0", (0, 1)),
+        ("1", (1, 1)),
+        ("1/3", (1, 3)),
+        (".999", (999, 1000)),
+    ] {
+        let expected = Ratio::new(expected.0, expected.1);
+        assert_eq!(clamp(s), expected, "input {:?}", s);
+        assert_eq!(strict(s).unwrap(), expected, "input {:?}", s);
+    }
+
+    assert_eq!(clamp("1.0001"), Ratio::new(1, 1));
+    assert_eq!(clamp("2147483647"), Ratio::new(1, 1));
+    assert_eq!(clamp("-1/2147483647"), Ratio::new(0, 1));
+    assert_eq!(clamp("-0"), Ratio::new(0, 1));
+    for s in ["1.0001", "-1e-9", "3/2"] {
+        assert_eq!(
+            *strict(s).unwrap_err().kind(),
+            RatioErrorKind::OutOfRange,
+            "input {:?}",
+            s
+        );
+    }
+
+    // Parse errors are reported regardless of the policy.
+    assert_eq!(
+        *from_str_flex_clamped01::<i32>("1/0", RangePolicy::Clamp)
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_chained_division() {
+    let parser = RationalParser::new().allow_chained_division(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+    let kind = |s: &str| *parse(s).unwrap_err().kind();
+
+    assert_eq!(parse("1/2/3").unwrap(), Ratio::new(1, 6));
+    assert_eq!(parse("8/4/2").unwrap(), Ratio::new(1, 1));
+    assert_eq!(parse(" -1 / 2 / 3 / 4 ").unwrap(), Ratio::new(-1, 24));
+    assert_eq!(parse("1_000/10/1_0").unwrap(), Ratio::new(10, 1));
+    assert_eq!(parse("3/4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(parse("1.5").unwrap(), Ratio::new(3, 2));
+
+    assert_eq!(kind("1/2/0"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind("1/0/2"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind("1/2147483647/2"), RatioErrorKind::Overflow);
+    assert_eq!(kind("1/2/2147483648"), RatioErrorKind::Overflow);
+    for s in [
+        "1/2/", "1//2", "1/2/-3", "1/2/+3", "1/2/3.5", "1/2/1e2", "1/2/x",
+    ] {
+        assert_eq!(kind(s), RatioErrorKind::ParseError, "input {:?}", s);
+    }
+    assert_eq!(kind("1.5/2/3"), RatioErrorKind::MixedFractionDecimal);
+
+    // Divisors stay plain integers whatever else is enabled.
+    let binary = parser.clone().allow_binary_exponent(true);
+    assert_eq!(binary.parse::<i32>("1/2/3").unwrap(), Ratio::new(1, 6));
+    for s in ["1/2/3p1", "1/2/3P1", "1/2/3p", "1/2/p1"] {
+        assert_eq!(
+            *binary.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    let grouped = parser.clone().grouping_separator(Some('\''));
+    assert_eq!(
+        grouped.parse::<i32>("1/2/1'000").unwrap(),
+        Ratio::new(1, 2000)
+    );
+    assert_eq!(
+        *grouped.parse::<i32>("1/2/1_000").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+
+    // Chained division is rejected by default.
+    check_invalid("1/2/3", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_ratio_from_parts() {
+    assert_eq!(ratio_from_parts(3, 4).unwrap(), Ratio::new(3, 4));
+    assert_eq!(ratio_from_parts(6, -4).unwrap(), Ratio::new(-3, 2));
+    assert_eq!(ratio_from_parts(0, 5).unwrap(), Ratio::new(0, 1));
+    assert_eq!(
+        *ratio_from_parts(3, 0).unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        ratio_from_parts(1_000, 2_000).unwrap(),
+        Rational32::from_str_flex("1_000/2_000").unwrap()
+    );
+
+    // Flipping the sign of the most negative value overflows.
+    let overflow = |numer: i32, denom: i32| {
+        assert_eq!(
+            *ratio_from_parts(numer, denom).unwrap_err().kind(),
+            RatioErrorKind::Overflow,
+            "parts {:?}",
+            (numer, denom)
+        );
+    };
+    overflow(i32::MIN, -1);
+    overflow(1, i32::MIN);
+    overflow(-3, i32::MIN);
+    // Unless reducing makes room first.
+    assert_eq!(
+        ratio_from_parts(i32::MIN, -2).unwrap(),
+        Ratio::from_integer(1 << 30)
+    );
+    assert_eq!(
+        ratio_from_parts(2, i32::MIN).unwrap(),
+        Ratio::new(-1, 1 << 30)
+    );
+    assert_eq!(
+        ratio_from_parts(i32::MIN, i32::MIN).unwrap(),
+        Ratio::from_integer(1)
+    );
+    assert_eq!(
+        ratio_from_parts(0, i32::MIN).unwrap(),
+        Ratio::from_integer(0)
+    );
+    assert_eq!(
+        ratio_from_parts(i32::MIN, 1).unwrap(),
+        Ratio::from_integer(i32::MIN)
+    );
+    assert_eq!(
+        ratio_from_parts(i32::MAX, -1).unwrap(),
+        Ratio::from_integer(-i32::MAX)
+    );
+    assert_eq!(ratio_from_parts(-4, -6).unwrap(), Ratio::new(2, 3));
+    assert_eq!(ratio_from_parts(7u32, 14).unwrap(), Ratio::new(1, 2));
+}
+
+#[test]
+fn test_currency() {
+    let amount = |s: &str| from_currency::<i64>(s).unwrap();
+    let kind = |s: &str| *from_currency::<i64>(s).unwrap_err().kind();
+
+    assert_eq!(amount("1,234.56"), Ratio::new(123456, 100));
+    assert_eq!(amount("$1,234.56"), Ratio::new(123456, 100));
+    assert_eq!(amount(" -$1,234,567.8 "), Ratio::new(-12345678, 10));
+    assert_eq!(amount("€0.50"), Ratio::new(1, 2));
+    assert_eq!(amount("£1234"), Ratio::new(1234, 1));
+    assert_eq!(amount("(1,234.56)"), Ratio::new(-123456, 100));
+    assert_eq!(amount("( $12.00 )"), Ratio::new(-12, 1));
+    assert_eq!(amount("999"), Ratio::new(999, 1));
+
+    for s in [
+        "",
+        "$",
+        "1,23",
+        "12,34.5",
+        "1,2345",
+        ",123",
+        "123,",
+        "1.234",
+        "1.",
+        ".5",
+        "(1.00",
+        "1.00)",
+        "(-1.00)",
+        "$$1",
+        "1$",
+        "+1",
+        "1e3",
+        "1,234.56.7",
+        "USD 1",
+    ] {
+        assert_eq!(kind(s), RatioErrorKind::ParseError, "input {:?}", s);
+    }
+    assert_eq!(
+        *from_currency::<i32>("$21,474,836.48").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_ratio_stats() {
+    let stats = ratio_stats(Vec::<String>::new()).unwrap();
+    assert_eq!(stats.count, 0);
+    assert!(stats.fits::<i8>());
+
+    let stats = ratio_stats(["127", "-1/128", "6/4"]).unwrap();
+    assert_eq!(
+        (stats.count, stats.max_numer, stats.max_denom),
+        (3, 127, 128)
+    );
+    assert!(stats.fits::<i16>());
+    assert!(!stats.fits::<i8>());
+
+    let stats = ratio_stats(["-170141183460469231731687303715884105727"]).unwrap();
+    assert_eq!(stats.max_numer, i128::MAX as u128);
+    assert!(stats.fits::<i128>());
+    assert!(!stats.fits::<i64>());
+
+    let (index, err) = ratio_stats(vec![String::from("1"), String::from("1/0")]).unwrap_err();
+    assert_eq!(index, 1);
+    assert_eq!(*err.kind(), RatioErrorKind::ZeroDenominator);
+}
+
+#[test]
+fn test_parse_flex_min_max() {
+    let items = ["0.5", "-1/3", "2e1", "1/2", "-0.3"];
+    assert_eq!(
+        parse_flex_min::<i32, _>(items).unwrap(),
+        Some(Ratio::new(-1, 3))
+    );
+    assert_eq!(
+        parse_flex_max::<i32, _>(items).unwrap(),
+        Some(Ratio::new(20, 1))
+    );
+    assert_eq!(
+        parse_flex_max::<i32, _>(vec![String::from("-2/4"), String::from("-0.5")]).unwrap(),
+        Some(Ratio::new(-1, 2))
+    );
+    assert_eq!(parse_flex_min::<i32, _>(Vec::<&str>::new()).unwrap(), None);
+
+    // Parsing stops at the first invalid item.
+    let mut seen = 0;
+    let items = ["1", "2", "1/0", "x"].into_iter().inspect(|_| seen += 1);
+    let (index, err) = parse_flex_min::<i32, _>(items).unwrap_err();
+    assert_eq!(index, 2);
+    assert_eq!(*err.kind(), RatioErrorKind::ZeroDenominator);
+    assert_eq!(seen, 3);
+}
+
+#[test]
+fn test_whitespace_separated() {
+    let kinds = |s: &str| {
+        parse_whitespace_separated::<i32>(s)
+            .map(|r| r.map_err(|e| *e.kind()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        kinds("1/2  0.75\t-3e2\r\n+4"),
+        [
+            Ok(Ratio::new(1, 2)),
+            Ok(Ratio::new(3, 4)),
+            Ok(Ratio::from_integer(-300)),
+            Ok(Ratio::from_integer(4)),
+        ]
+    );
+    assert_eq!(kinds(""), []);
+    assert_eq!(kinds(" \t\n"), []);
+
+    // Whitespace always separates tokens.
+    assert_eq!(
+        kinds("3 / 2"),
+        [
+            Ok(Ratio::from_integer(3)),
+            Err(RatioErrorKind::ParseError),
+            Ok(Ratio::from_integer(2)),
+        ]
+    );
+    assert_eq!(
+        kinds("- 3"),
+        [Err(RatioErrorKind::ParseError), Ok(Ratio::from_integer(3))]
+    );
+    assert_eq!(
+        kinds("1/0 x 5"),
+        [
+            Err(RatioErrorKind::ZeroDenominator),
+            Err(RatioErrorKind::ParseError),
+            Ok(Ratio::from_integer(5)),
+        ]
+    );
+
+    // Only ASCII whitespace separates; other whitespace is part of a token.
+    assert_eq!(kinds("1\u{a0}2"), [Err(RatioErrorKind::ParseError)]);
+}
+
+#[test]
+fn test_csv_column() {
+    let kinds = |csv: &[u8], column, header| {
+        parse_csv_column::<i32, _>(csv, column, header)
+            .map(|r| r.map_err(|e| *e.kind()))
+            .collect::<Vec<_>>()
+    };
+
+    let csv = b"id,value,note\r\n1, 1/2 ,a\n2,0.75,b\n3,,c\n4\n\n5,1/0,d\n6,3e2";
+    assert_eq!(
+        kinds(csv, 1, true),
+        [
+            Ok(Ratio::new(1, 2)),
+            Ok(Ratio::new(3, 4)),
+            Err(RatioErrorKind::ParseError),
+            Err(RatioErrorKind::ParseError),
+            Err(RatioErrorKind::ParseError),
+            Err(RatioErrorKind::ZeroDenominator),
+            Ok(Ratio::from_integer(300)),
+        ]
+    );
+    assert_eq!(
+        kinds(csv, 0, true)[..2],
+        [Ok(Ratio::from_integer(1)), Ok(Ratio::from_integer(2))]
+    );
+    assert_eq!(kinds(csv, 0, false)[0], Err(RatioErrorKind::ParseError));
+    assert_eq!(kinds(b"1/3", 0, false), [Ok(Ratio::new(1, 3))]);
+    assert_eq!(kinds(b"header only\n", 0, true), []);
+    assert_eq!(kinds(b"", 0, false), []);
+
+    // Invalid UTF-8 ends the iteration with an error.
+    assert_eq!(
+        kinds(b"1\n\xff\n2\n", 0, false),
+        [Ok(Ratio::from_integer(1)), Err(RatioErrorKind::ParseError)]
+    );
+
+    // Rows are read lazily.
+    let mut rows = parse_csv_column::<i32, _>(&b"1\n2\n"[..], 0, false);
+    assert_eq!(rows.next(), Some(Ok(Ratio::from_integer(1))));
+}
+
+#[test]
+fn test_from_bytes_flex() {
+    assert_eq!(
+        from_bytes_flex::<i32>(b" 1.5e1 "),
+        Ok(Ratio::from_integer(15))
+    );
+    let kind = |bytes: &[u8]| *from_bytes_flex::<i32>(bytes).unwrap_err().kind();
+    assert_eq!(kind(b"\xff"), RatioErrorKind::ParseError);
+    // A truncated multi-byte character is not valid UTF-8.
+    assert_eq!(kind(&"1/2÷".as_bytes()[..4]), RatioErrorKind::ParseError);
+    assert_eq!(kind(b""), RatioErrorKind::ParseError);
+    assert_eq!(kind(b"1/0"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind(b"99999999999"), RatioErrorKind::Overflow);
+}
+
+#[test]
+fn test_expected_decimal_digits() {
+    let input = "0.00000095367431640625";
+    assert_eq!(
+        *Rational32::from_str_flex(input).unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+
+    let hinted = |digits| RationalParser::new().expected_decimal_digits(digits);
+    assert_eq!(
+        hinted(20).parse::<i32>(input).unwrap(),
+        Ratio::new(1, 1048576)
+    );
+    assert_eq!(
+        hinted(30).parse::<i32>("-0.000244140625").unwrap(),
+        Ratio::new(-1, 4096)
+    );
+    // An `i64` intermediate is enough for a scale of 10^12.
+    assert_eq!(
+        hinted(12).parse::<i32>("0.000244140625").unwrap(),
+        Ratio::new(1, 4096)
+    );
+    assert_eq!(
+        hinted(12).parse::<i8>("0.015625e1").unwrap(),
+        Ratio::new(5, 32)
+    );
+
+    // A hint that is too small for the scale does not help.
+    assert_eq!(
+        *hinted(18).parse::<i32>(input).unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    // The reduced value must still fit.
+    let err = hinted(20).parse::<i32>("0.1234567890123").unwrap_err();
+    assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Numerator));
+    let err = hinted(20).parse::<i8>("0.001").unwrap_err();
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Denominator));
+
+    // Values that fit are unaffected, and other errors are kept.
+    assert_eq!(hinted(20).parse::<i32>("3/4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(
+        *hinted(20).parse::<i32>("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        hinted(20)
+            .on_overflow(OverflowMode::NearestRepresentable)
+            .parse::<i32>(input)
+            .unwrap(),
+        Ratio::new(1, 1048576)
+    );
+}
+
+#[test]
+fn test_reduce_before_overflow() {
+    let parser = RationalParser::new().reduce_before_overflow(true);
+
+    for (s, expected) in [
+        ("2000000000/4000000000", Ratio::new(1, 2)),
+        ("-4000000000/6000000000", Ratio::new(-2, 3)),
+        ("6000000000/3000000000", Ratio::from_integer(2)),
+        (" 10_000_000_000 / 20_000_000_000 ", Ratio::new(1, 2)),
+        (
+            "170141183460469231731687303715884105727/170141183460469231731687303715884105727",
+            Ratio::from_integer(1),
+        ),
+    ] {
+        assert_eq!(
+            *Rational32::from_str_flex(s).unwrap_err().kind(),
+            RatioErrorKind::Overflow,
+            "input {:?}",
+            s
+        );
+        assert_eq!(parser.parse::<i32>(s).unwrap(), expected, "input {:?}", s);
+    }
+    assert_eq!(parser.parse::<i8>("200/400").unwrap(), Ratio::new(1, 2));
+
+    // Values that do not reduce enough still overflow.
+    let err = parser.parse::<i32>("3000000001/4000000000").unwrap_err();
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Numerator));
+    let err = parser.parse::<i32>("1/4000000000").unwrap_err();
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Denominator));
+    assert_eq!(
+        *parser
+            .parse::<i32>("1/1000000000000000000000000000000000000000")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+
+    // Decimals are not affected.
+    assert_eq!(
+        *parser
+            .parse::<i32>("0.00000095367431640625")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        parser
+            .clone()
+            .allow_chained_division(true)
+            .parse::<i32>("4000000000/2/4000000000")
+            .unwrap(),
+        Ratio::new(1, 2)
+    );
+    assert_eq!(
+        parser
+            .expected_decimal_digits(5)
+            .parse::<i32>("-20000000000000000000/40000000000000000000")
+            .unwrap(),
+        Ratio::new(-1, 2)
+    );
+}
+
+#[test]
+fn test_parse_with_report() {
+    let report = |parser: &RationalParser, s: &str| {
+        let (value, report) = parser.parse_with_report::<i32>(s).unwrap();
+        assert_eq!(value, parser.parse::<i32>(s).unwrap(), "input {:?}", s);
+        report.reduced_to_fit
+    };
+
+    let reduce = RationalParser::new().reduce_before_overflow(true);
+    assert!(report(&reduce, "2000000000/4000000000"));
+    assert!(report(&reduce, "-4000000000/6000000000"));
+    assert!(!report(&reduce, "1000000/2000000"));
+    assert!(!report(&reduce, "0.5"));
+
+    let hinted = RationalParser::new().expected_decimal_digits(20);
+    assert!(report(&hinted, "0.00000095367431640625"));
+    assert!(!report(&hinted, "0.25"));
+
+    // Without widening, nothing is reduced to fit.
+    let plain = RationalParser::new();
+    assert_eq!(
+        plain.parse_with_report::<i32>("3/4").unwrap(),
+        (Ratio::new(3, 4), ParseReport::default())
+    );
+    assert!(!report(
+        &plain
+            .clone()
+            .on_overflow(OverflowMode::NearestRepresentable),
+        "0.123456789012345"
+    ));
+
+    // An approximation is not a reduction.
+    let nearest = reduce
+        .clone()
+        .on_overflow(OverflowMode::NearestRepresentable);
+    assert!(!report(&nearest, "3000000001/4000000000"));
+    assert!(report(&nearest, "2000000000/4000000000"));
+
+    // Errors are the same as from `parse`.
+    for s in ["3000000001/4000000000", "1/0", "x"] {
+        assert_eq!(
+            reduce.parse_with_report::<i32>(s).unwrap_err(),
+            reduce.parse::<i32>(s).unwrap_err(),
+            "input {:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_nearest_representable() {
+    let parser = RationalParser::new().on_overflow(OverflowMode::NearestRepresentable);
+
+    assert_eq!(
+        parser.parse::<i32>("0.123456789012345").unwrap(),
+        Ratio::new(115226266, 933332763)
+    );
+    assert_eq!(
+        *Rational32::from_str_flex("0.123456789012345")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+
+    // Compared against a brute-force search over all candidates.
+    assert_eq!(parser.parse::<i8>("3.14159").unwrap(), Ratio::new(22, 7));
+    assert_eq!(
+        parser.parse::<i8>("0.123456789").unwrap(),
+        Ratio::new(10, 81)
+    );
+    assert_eq!(parser.parse::<i8>("-0.7071").unwrap(), Ratio::new(-70, 99));
+
+    // Values that fit are not approximated.
+    assert_eq!(parser.parse::<i8>("127/3").unwrap(), Ratio::new(127, 3));
+    assert_eq!(parser.parse::<i8>("200/400").unwrap(), Ratio::new(1, 2));
+
+    // Values beyond the range saturate, tiny values round to zero.
+    assert_eq!(
+        parser.parse::<i32>("1e30").unwrap(),
+        Ratio::new(i32::MAX, 1)
+    );
+    assert_eq!(
+        parser.parse::<i32>("-1e30").unwrap(),
+        Ratio::new(-i32::MAX, 1)
+    );
+    assert_eq!(parser.parse::<i32>("1e-30").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parser.parse::<i8>("0.003").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parser.parse::<i8>("0.006").unwrap(), Ratio::new(1, 127));
+
+    // The exact value must still fit in a `Ratio<i128>`.
+    assert_eq!(
+        *parser.parse::<i32>("1e40").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        *parser.parse::<i32>("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_zero_denominator_saturate() {
+    let parser = RationalParser::new().zero_denominator_policy(ZeroDenomPolicy::Saturate);
+
+    assert_eq!(
+        parser.parse::<i32>("1/0").unwrap(),
+        Ratio::from_integer(i32::MAX)
+    );
+    assert_eq!(
+        parser.parse::<i32>("-5/0").unwrap(),
+        Ratio::from_integer(-i32::MAX)
+    );
+    assert_eq!(
+        parser.parse::<i8>(" 3 / 0_0 ").unwrap(),
+        Ratio::from_integer(127)
+    );
+    assert_eq!(
+        parser.parse::<i8>("-128/0").unwrap(),
+        Ratio::from_integer(-127)
+    );
+    assert_eq!(
+        parser.parse::<i64>("1/0").unwrap(),
+        Ratio::from_integer(i64::MAX)
+    );
+    assert_eq!(
+        parser.parse::<i128>("1/0").unwrap(),
+        Ratio::from_integer(i128::MAX)
+    );
+
+    // The sign of the denominator counts, too.
+    let signed = parser.clone().allow_signed_denominator(true);
+    assert_eq!(
+        signed.parse::<i32>("1/-0").unwrap(),
+        Ratio::from_integer(-i32::MAX)
+    );
+    assert_eq!(
+        signed.parse::<i32>("-1/-0").unwrap(),
+        Ratio::from_integer(i32::MAX)
+    );
+
+    // The sentinel is an ordinary value for any suffix.
+    let percent = parser.clone().allow_percent(true);
+    assert_eq!(
+        percent.parse::<i32>("1/0%").unwrap(),
+        Ratio::new(i32::MAX, 100)
+    );
+
+    for s in ["0/0", "-0/0"] {
+        assert_eq!(
+            *parser.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::ZeroDenominator,
+            "input {:?}",
+            s
+        );
+    }
+    let chained = parser.allow_chained_division(true);
+    assert_eq!(
+        *chained.parse::<i32>("1/2/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+
+    // Other values are not affected.
+    assert_eq!(
+        RationalParser::new()
+            .zero_denominator_policy(ZeroDenomPolicy::Saturate)
+            .parse::<i32>("3/4")
+            .unwrap(),
+        Ratio::new(3, 4)
+    );
+    assert_eq!(
+        *Rational32::from_str_flex("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_trimmed() {
+    let trimmed = |s: &str| {
+        let (_, t) = parse_flex_trimmed::<i32>(s).unwrap();
+        (t.whitespace, t.leading_zeros)
+    };
+
+    assert_eq!(trimmed("3/4"), (false, false));
+    assert_eq!(trimmed("0"), (false, false));
+    assert_eq!(trimmed("0.05"), (false, false));
+    assert_eq!(trimmed("-0e0"), (false, false));
+    assert_eq!(trimmed("10/20"), (false, false));
+    assert_eq!(trimmed(" 3/4"), (true, false));
+    assert_eq!(trimmed("3/4\n"), (true, false));
+    assert_eq!(trimmed("\u{a0}3"), (true, false));
+    assert_eq!(trimmed("00"), (false, true));
+    assert_eq!(trimmed("-007"), (false, true));
+    assert_eq!(trimmed("0_1"), (false, true));
+    assert_eq!(trimmed("1/02"), (false, true));
+    assert_eq!(trimmed("1e-05"), (false, true));
+    assert_eq!(trimmed("00.5"), (false, true));
+    assert_eq!(trimmed("  0013/002 "), (true, true));
+
+    assert_eq!(
+        parse_flex_trimmed::<i32>(" 0013/002 ").unwrap(),
+        (
+            Ratio::new(13, 2),
+            Trimmed {
+                whitespace: true,
+                leading_zeros: true
+            }
+        )
+    );
+    assert_eq!(
+        *parse_flex_trimmed::<i32>(" 1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_integer_zeros_cancel_exponent() {
+    assert_eq!(components("100e-2"), (1, 1));
+    assert_eq!(components("250e-1"), (25, 1));
+    assert_eq!(components("100000000000e-11"), (1, 1));
+    assert_eq!(components("-1_000_000_000_000e-12"), (-1, 1));
+    assert_eq!(components("30000000000000e-14"), (3, 10));
+    assert_eq!(components("1000e-11"), (1, 100000000));
+    assert_eq!(components("2000000000000e-3"), (2000000000, 1));
+    assert_eq!(components("0000e-30"), (0, 1));
+    assert_eq!(components("120e1"), (1200, 1));
+
+    // Decimals keep their own scale.
+    assert_eq!(components("100.5e-1"), (201, 20));
+    assert_eq!(components("10000000000.0e-10"), (1, 1));
+
+    // Only zeros that cancel help, the rest must still fit.
+    check_invalid("100000000000e-1", RatioErrorKind::Overflow);
+    check_invalid("1000e-30", RatioErrorKind::Overflow);
+}
+
+#[test]
+fn test_trailing_point_with_exponent() {
+    assert_eq!(components("3.e5"), (300000, 1));
+    assert_eq!(components("3.E5"), (300000, 1));
+    assert_eq!(components("3.e+5"), (300000, 1));
+    assert_eq!(components("-3.e-2"), (-3, 100));
+    assert_eq!(components("0.e0"), (0, 1));
+    assert_eq!(components("-0.e-7"), (0, 1));
+    assert_eq!(components("1_0.e1"), (100, 1));
+    assert_eq!(components("120.e-1"), (12, 1));
+    assert_eq!(components(" 3.e0 "), (3, 1));
+
+    // The empty decimal does not scale the value.
+    assert_eq!(components("3.e0"), components("3"));
+    assert_eq!(components("25.e-1"), components("25e-1"));
+    assert_eq!(components("2147483647.e0"), (i32::MAX, 1));
+
+    check_invalid("3.e", RatioErrorKind::DanglingExponent);
+    check_invalid("3.e-", RatioErrorKind::DanglingExponent);
+    check_invalid("3.e5.", RatioErrorKind::ParseError);
+    check_invalid("3..e5", RatioErrorKind::ParseError);
+    check_invalid("3. e5", RatioErrorKind::ParseError);
+    check_invalid("3.e10", RatioErrorKind::Overflow);
+}
+
+#[test]
+fn test_f64() {
+    assert_eq!(parse_flex_f64("1/3").unwrap(), 1.0 / 3.0);
+    assert_eq!(parse_flex_f64("0.1").unwrap(), 0.1);
+    assert_eq!(parse_flex_f64("-7/2").unwrap(), -3.5);
+    assert_eq!(parse_flex_f64("1e-30").unwrap(), 1e-30);
+    assert_eq!(parse_flex_f64("0").unwrap(), 0.0);
+    assert_eq!(parse_flex_f64("1e38").unwrap(), 1e38);
+
+    // Dividing the rounded terms would give 1.0491053991975303.
+    let s = "613320097702778274633757756/584612469034962694232239940";
+    assert_eq!(parse_flex_f64(s).unwrap(), 1.04910539919753);
+
+    assert_eq!(
+        *parse_flex_f64("1e39").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        *parse_flex_f64("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_overflow_source() {
+    let source = |s: &str| {
+        let err = Rational32::from_str_flex(s).unwrap_err();
+        assert_eq!(*err.kind(), RatioErrorKind::Overflow, "input {:?}", s);
+        err.overflow_source()
+    };
+    use OverflowSource::*;
+
+    assert_eq!(source("2147483648/1"), Some(Numerator));
+    assert_eq!(source("-2147483649/3"), Some(Numerator));
+    assert_eq!(source("1/2147483648"), Some(Denominator));
+    assert_eq!(source("1 / 99_999_999_999"), Some(Denominator));
+    // The numerator is checked first.
+    assert_eq!(source("2147483648/2147483648"), Some(Numerator));
+
+    assert_eq!(source("2147483648"), Some(Numerator));
+    assert_eq!(source("2147483648.5"), Some(Numerator));
+    assert_eq!(source("1.5e10"), Some(Numerator));
+    assert_eq!(source("1e-10"), Some(Denominator));
+    assert_eq!(source("0.00000000001"), Some(Denominator));
+    assert_eq!(source("1e99"), Some(Numerator));
+    assert_eq!(source("1e-99"), Some(Denominator));
+
+    let err = Rational32::from_str_flex("1/0").unwrap_err();
+    assert_eq!(err.overflow_source(), None);
+
+    // The source is not part of equality, so errors still compare by kind.
+    let numer = Rational32::from_str_flex("2147483648/1").unwrap_err();
+    let denom = Rational32::from_str_flex("1/2147483648").unwrap_err();
+    assert_eq!(numer, denom);
+    assert_eq!(numer, ParseRatioError::from(RatioErrorKind::Overflow));
+    assert_ne!(numer, ParseRatioError::from(RatioErrorKind::ParseError));
+}
+
+#[test]
+fn test_exponent_base() {
+    let base2 = RationalParser::new().exponent_base(2);
+    let parse = |s: &str| base2.parse::<i32>(s).unwrap();
+
+    assert_eq!(parse("3e4"), Ratio::from_integer(48));
+    assert_eq!(parse("3E-1"), Ratio::new(3, 2));
+    assert_eq!(parse("-1.5e-1"), Ratio::new(-3, 4));
+    assert_eq!(parse("0.1e3"), Ratio::new(4, 5));
+    assert_eq!(parse("100e-2"), Ratio::from_integer(25));
+    assert_eq!(parse("7e0"), Ratio::from_integer(7));
+    assert_eq!(parse("0e99"), Ratio::from_integer(0));
+    assert_eq!(parse("3/4"), Ratio::new(3, 4));
+
+    let base16 = RationalParser::new().exponent_base(16);
+    assert_eq!(
+        base16.parse::<i32>("1e2").unwrap(),
+        Ratio::from_integer(256)
+    );
+    assert_eq!(base16.parse::<i32>("1e-1").unwrap(), Ratio::new(1, 16));
+    assert_eq!(
+        *base16.parse::<i32>("1e8").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        base16
+            .clone()
+            .allow_binary_exponent(true)
+            .parse::<i32>("3p2")
+            .unwrap(),
+        Ratio::from_integer(12)
+    );
+
+    // With a fraction exponent, factors of the base cancel first.
+    let fraction = base16.clone().allow_fraction_exponent(true);
+    assert_eq!(
+        fraction.parse::<i8>("1/16e1").unwrap(),
+        Ratio::from_integer(1)
+    );
+    assert_eq!(fraction.parse::<i8>("32/3e-1").unwrap(), Ratio::new(2, 3));
+
+    // A base beyond the target type only fails if it is used.
+    let wide = RationalParser::new().exponent_base(1000);
+    assert_eq!(wide.parse::<i8>("3e0").unwrap(), Ratio::from_integer(3));
+    assert_eq!(
+        wide.parse::<i8>("3e1").unwrap_err().overflow_source(),
+        Some(OverflowSource::Numerator)
+    );
+    assert_eq!(
+        wide.parse::<i8>("3e-1").unwrap_err().overflow_source(),
+        Some(OverflowSource::Denominator)
+    );
+    assert_eq!(wide.parse::<i32>("3e-1").unwrap(), Ratio::new(3, 1000));
+
+    // The default base stays ten.
+    assert_eq!(
+        RationalParser::new()
+            .exponent_base(10)
+            .parse::<i32>("2.147483647e9")
+            .unwrap(),
+        Ratio::from_integer(2147483647)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_exponent_base_one() {
+    let _ = RationalParser::new().exponent_base(1);
+}
+
+#[test]
+fn test_binary_exponent() {
+    let parser = RationalParser::new().allow_binary_exponent(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+    let kind = |s: &str| *parser.parse::<i32>(s).unwrap_err().kind();
+
+    assert_eq!(parse("3p4").unwrap(), Ratio::new(48, 1));
+    assert_eq!(parse("3P4").unwrap(), Ratio::new(48, 1));
+    assert_eq!(parse("3p+4").unwrap(), Ratio::new(48, 1));
+    assert_eq!(parse("3p-1").unwrap(), Ratio::new(3, 2));
+    assert_eq!(parse("-1.5p-1").unwrap(), Ratio::new(-3, 4));
+    assert_eq!(parse("0.1p3").unwrap(), Ratio::new(4, 5));
+    assert_eq!(parse("100p-2").unwrap(), Ratio::new(25, 1));
+    assert_eq!(parse("1_0p1_0").unwrap(), Ratio::new(10240, 1));
+    assert_eq!(parse("0p99").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parse(".5p0").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse("1p30").unwrap(), Ratio::new(1 << 30, 1));
+    assert_eq!(parse("1p-30").unwrap(), Ratio::new(1, 1 << 30));
+    // Decimal exponents are unaffected.
+    assert_eq!(parse("3e4").unwrap(), Ratio::new(30000, 1));
+
+    assert_eq!(kind("3p"), RatioErrorKind::DanglingExponent);
+    assert_eq!(kind("3p-"), RatioErrorKind::DanglingExponent);
+    assert_eq!(kind("3p1e1"), RatioErrorKind::ParseError);
+    assert_eq!(kind("3/4p1"), RatioErrorKind::ParseError);
+    assert_eq!(kind("p1"), RatioErrorKind::ParseError);
+    assert_eq!(kind("1p31"), RatioErrorKind::Overflow);
+    assert_eq!(kind("1.1p-31"), RatioErrorKind::Overflow);
+
+    // Off by default.
+    check_invalid("3p4", RatioErrorKind::ParseError);
+    check_invalid("3p", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_empty_denominator() {
+    let parser = RationalParser::new().allow_empty_denominator(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    for s in ["3/", "3 /", "3/ ", " 3 / ", "+3/", "3_0/"] {
+        let expected = if s.contains('_') { 30 } else { 3 };
+        assert_eq!(
+            parse(s).unwrap(),
+            Ratio::from_integer(expected),
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(parse("-7/").unwrap(), Ratio::from_integer(-7));
+    assert_eq!(parse("0/").unwrap(), Ratio::from_integer(0));
+    assert_eq!(parse("3/4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(
+        *parse("3/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+
+    for s in ["/", " / ", "3//", "3/e2", "3.5/", "3/ /", "3 / x"] {
+        assert!(parse(s).is_err(), "input {:?}", s);
+    }
+    let signed = parser.clone().allow_signed_denominator(true);
+    assert!(signed.parse::<i32>("3/-").is_err());
+    let exponent = parser.allow_fraction_exponent(true);
+    assert!(exponent.parse::<i32>("3/e2").is_err());
+
+    // The default still rejects a missing denominator.
+    assert_eq!(
+        *RationalParser::new().parse::<i32>("3/").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+}
+
+#[test]
+fn test_implicit_mantissa() {
+    let parser = RationalParser::new().allow_implicit_mantissa(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    assert_eq!(parse("e5").unwrap(), Ratio::from_integer(100_000));
+    assert_eq!(parse("E+5").unwrap(), Ratio::from_integer(100_000));
+    assert_eq!(parse("-e3").unwrap(), Ratio::from_integer(-1000));
+    assert_eq!(parse("+e3").unwrap(), Ratio::from_integer(1000));
+    assert_eq!(parse("e-3").unwrap(), Ratio::new(1, 1000));
+    assert_eq!(parse("-e-2").unwrap(), Ratio::new(-1, 100));
+    assert_eq!(parse(" e0 ").unwrap(), Ratio::from_integer(1));
+    assert_eq!(parse("e0_3").unwrap(), Ratio::from_integer(1000));
+    assert_eq!(parse("2e3").unwrap(), Ratio::from_integer(2000));
+
+    // The implicit mantissa follows the other exponent settings.
+    let binary = parser.clone().allow_binary_exponent(true);
+    assert_eq!(binary.parse::<i32>("p4").unwrap(), Ratio::from_integer(16));
+    let base = parser.clone().exponent_base(2);
+    assert_eq!(base.parse::<i32>("-e-3").unwrap(), Ratio::new(-1, 8));
+    let signed = parser.clone().require_sign(true);
+    assert_eq!(
+        signed.parse::<i32>("+e2").unwrap(),
+        Ratio::from_integer(100)
+    );
+    assert_eq!(
+        *signed.parse::<i32>("e2").unwrap_err().kind(),
+        RatioErrorKind::MissingSign
+    );
+
+    // Large exponents overflow like any other.
+    assert_eq!(parse("e9").unwrap(), Ratio::from_integer(1_000_000_000));
+    assert_eq!(parse("-e9").unwrap(), Ratio::from_integer(-1_000_000_000));
+    let err = parse("e10").unwrap_err();
+    assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Numerator));
+    let err = parse("-e-10").unwrap_err();
+    assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+    assert_eq!(err.overflow_source(), Some(OverflowSource::Denominator));
+    assert_eq!(
+        *parse("e99999999999").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+    assert_eq!(
+        parser.parse::<i64>("e18").unwrap(),
+        Ratio::from_integer(10i64.pow(18))
+    );
+    assert_eq!(
+        *parser.parse::<i64>("e19").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+
+    // Only a bare exponent has an implicit mantissa.
+    assert_eq!(
+        *parse("e").unwrap_err().kind(),
+        RatioErrorKind::DanglingExponent
+    );
+    for s in [".e5", "/e5", "-", "e5/2", "ee5", "e 5", "- e5"] {
+        assert!(parse(s).is_err(), "input {:?}", s);
+    }
+
+    // The default rejects a missing mantissa.
+    for s in ["e5", "-e3", "e-3"] {
+        assert_eq!(
+            *RationalParser::new().parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_signed_denominator() {
+    let parser = RationalParser::new().allow_signed_denominator(true);
+    let parse = |s: &str| parser.parse::<i32>(s).unwrap();
+
+    for (numer_sign, numer_neg) in [("", false), ("+", false), ("-", true)] {
+        for (denom_sign, denom_neg) in [("", false), ("+", false), ("-", true)] {
+            let s = format!("{}3/{}2", numer_sign, denom_sign);
+            let expected = if numer_neg != denom_neg { -3 } else { 3 };
+            assert_eq!(parse(&s), Ratio::new(expected, 2), "input {:?}", s);
+        }
+    }
+    assert_eq!(parse(" 6 / -4 "), Ratio::new(-3, 2));
+    assert_eq!(parse("-1_0/-1_00"), Ratio::new(1, 10));
+    assert_eq!(parse("0/-5"), Ratio::new(0, 1));
+
+    for s in ["3/-0", "3/+0", "-3/-0"] {
+        assert_eq!(
+            *parser.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::ZeroDenominator
+        );
+    }
+    for s in ["3.5/-2", "3/-2.5"] {
+        assert_eq!(
+            *parser.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::MixedFractionDecimal,
+            "input {:?}",
+            s
+        );
+    }
+    for s in ["3/--2", "3/- 2", "3/-", "3/-2e1"] {
+        assert_eq!(
+            *parser.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *RationalParser::new()
+            .allow_signed_denominator(true)
+            .require_sign(true)
+            .parse::<i32>("3/-2")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::MissingSign
+    );
+
+    // Rejected by default.
+    check_invalid("3/-2", RatioErrorKind::ParseError);
+    check_invalid("-3/-2", RatioErrorKind::ParseError);
+    check_invalid("3/+2", RatioErrorKind::ParseError);
+    check_invalid("3/-0", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_integer() {
+    let integer = |s: &str| from_str_flex_integer::<i32>(s).map(|r| r.to_integer());
+
+    assert_eq!(integer("3"), Ok(3));
+    assert_eq!(integer("3/1"), Ok(3));
+    assert_eq!(integer("6/2"), Ok(3));
+    assert_eq!(integer("-12/4"), Ok(-3));
+    assert_eq!(integer("2.50e1"), Ok(25));
+    assert_eq!(integer("1.0"), Ok(1));
+    assert_eq!(integer("-0"), Ok(0));
+    assert_eq!(integer("500e-2"), Ok(5));
+
+    for s in ["3/2", "0.5", "-1/3", "1e-1", "2.5"] {
+        assert_eq!(
+            *integer(s).unwrap_err().kind(),
+            RatioErrorKind::NonInteger,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *integer("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        *integer("x").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+}
+
+#[test]
+fn test_empty_numerator() {
+    assert_eq!(components(".6"), (3, 5));
+    assert_eq!(components("+.6"), (3, 5));
+    assert_eq!(components("-.6"), (-3, 5));
+    assert_eq!(components(".6e2"), (60, 1));
+    assert_eq!(components("-.6E-2"), (-3, 500));
+    assert_eq!(components("+.6e+1"), (6, 1));
+    assert_eq!(components(".0"), (0, 1));
+    assert_eq!(components("-.0"), (0, 1));
+    assert_eq!(components(".0e5"), (0, 1));
+    assert_eq!(components(".000_1"), (1, 10000));
+    assert_eq!(components(" -.5 "), (-1, 2));
+
+    // The decimal point needs digits on at least one side, whatever the sign
+    // and exponent.
+    for sign in ["", "+", "-"] {
+        for exp in ["", "e1", "e-1", "E+1"] {
+            let s = format!("{}.{}", sign, exp);
+            check_invalid(&s, RatioErrorKind::ParseError);
+        }
+        check_invalid(sign, RatioErrorKind::ParseError);
+        check_invalid(&format!("{}e1", sign), RatioErrorKind::ParseError);
+        check_invalid(&format!("{}_5", sign), RatioErrorKind::ParseError);
+        check_invalid(&format!("{}/2", sign), RatioErrorKind::ParseError);
+        check_invalid(&format!("{}._5", sign), RatioErrorKind::ParseError);
+    }
+    check_invalid(".6/2", RatioErrorKind::MixedFractionDecimal);
+    check_invalid(". 6", RatioErrorKind::ParseError);
+    check_invalid("- .6", RatioErrorKind::ParseError);
+    check_invalid(".6e", RatioErrorKind::DanglingExponent);
+}
+
+#[test]
+fn test_typed() {
+    let typed = |s: &str| from_str_flex_typed::<i64>(s);
+    let kind = |s: &str| *typed(s).unwrap_err().kind();
+
+    assert_eq!(typed("3/4_i32").unwrap(), Ratio::new(3, 4));
+    assert_eq!(typed(" -1.5e2_i16 ").unwrap(), Ratio::new(-150, 1));
+    assert_eq!(typed("127_i8").unwrap(), Ratio::new(127, 1));
+    assert_eq!(typed("1_000_i64").unwrap(), Ratio::new(1000, 1));
+    assert_eq!(typed("2147483648_i128").unwrap(), Ratio::new(2147483648, 1));
+    // Without a suffix, only `T` is checked.
+    assert_eq!(typed("1_000").unwrap(), Ratio::new(1000, 1));
+    assert_eq!(typed("2147483648").unwrap(), Ratio::new(2147483648, 1));
+
+    assert_eq!(kind("128_i8"), RatioErrorKind::Overflow);
+    assert_eq!(kind("1/128_i8"), RatioErrorKind::Overflow);
+    assert_eq!(kind("1e5_i16"), RatioErrorKind::Overflow);
+    assert_eq!(kind("2147483648_i32"), RatioErrorKind::Overflow);
+    // The reduced value has to fit, not the parts as written.
+    assert_eq!(typed("200/4_i8").unwrap(), Ratio::from_integer(50));
+    assert_eq!(typed("-300/600_i8").unwrap(), Ratio::new(-1, 2));
+    assert_eq!(typed("1.000_i8").unwrap(), Ratio::from_integer(1));
+    assert_eq!(typed("128/128_i8").unwrap(), Ratio::from_integer(1));
+    assert_eq!(typed("-256/2_i8").unwrap(), Ratio::from_integer(-128));
+    assert_eq!(kind("256/2_i8"), RatioErrorKind::Overflow);
+    assert_eq!(
+        typed("2/256_i8").unwrap_err().overflow_source(),
+        Some(OverflowSource::Denominator)
+    );
+    // `T` still applies when the suffix type is wider.
+    assert_eq!(kind("1e20_i128"), RatioErrorKind::Overflow);
+
+    assert_eq!(kind("3_u8"), RatioErrorKind::ParseError);
+    assert_eq!(kind("3_f64"), RatioErrorKind::ParseError);
+    assert_eq!(kind("3_I32"), RatioErrorKind::ParseError);
+    assert_eq!(kind("3_i32_i32"), RatioErrorKind::ParseError);
+    assert_eq!(kind("3 _i32"), RatioErrorKind::ParseError);
+    assert_eq!(kind("_i32"), RatioErrorKind::ParseError);
+    assert_eq!(kind("1/0_i32"), RatioErrorKind::ZeroDenominator);
+}
+
+#[test]
+fn test_require_terminating_decimal() {
+    let parser = RationalParser::new().require_terminating_decimal(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    assert_eq!(parse("1/4").unwrap(), Ratio::new(1, 4));
+    assert_eq!(parse("0.75").unwrap(), Ratio::new(3, 4));
+    assert_eq!(parse("-7/20").unwrap(), Ratio::new(-7, 20));
+    assert_eq!(parse("3/6").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse("6/3").unwrap(), Ratio::new(2, 1));
+    assert_eq!(parse("1/1024").unwrap(), Ratio::new(1, 1024));
+    assert_eq!(parse("1/390625").unwrap(), Ratio::new(1, 390625));
+    assert_eq!(parse("1e-9").unwrap(), Ratio::new(1, 1000000000));
+    assert_eq!(parse("0").unwrap(), Ratio::new(0, 1));
+
+    for s in ["1/3", "5/6", "-1/7", "1/30", "2/2147483647"] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::NonTerminating,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *parse("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(Rational32::from_str_flex("1/3").unwrap(), Ratio::new(1, 3));
+}
+
+#[test]
+fn test_long_digit_runs() {
+    // Every length around the eight-digit chunks, with distinct digits.
+    let digits = "9182736450".repeat(4);
+    for len in 1..=38 {
+        let s = &digits[..len];
+        let expected: i128 = s.parse().unwrap();
+        let r = Ratio::<i128>::from_str_flex(s).unwrap();
+        assert_eq!(*r.numer(), expected, "input {:?}", s);
+        let r = Ratio::<i128>::from_str_flex(&format!("1/{}", s)).unwrap();
+        assert_eq!(*r.denom(), expected, "input {:?}", s);
+    }
+    assert_eq!(
+        components("0.000_000_000_000_000_000_000_000_000_1e27"),
+        (1, 10)
+    );
+
+    // Overflow is detected at the exact boundary of each type.
+    for (max, over) in [
+        ("9223372036854775807", "9223372036854775808"),
+        ("000009223372036854775807", "99999999999999999999"),
+    ] {
+        assert!(Rational64::from_str_flex(max).is_ok());
+        assert_eq!(
+            *Rational64::from_str_flex(over).unwrap_err().kind(),
+            RatioErrorKind::Overflow
+        );
+    }
+    let max = i128::MAX.to_string();
+    assert_eq!(
+        *Ratio::<i128>::from_str_flex(&max).unwrap().numer(),
+        i128::MAX
+    );
+    assert_eq!(
+        *Ratio::<i128>::from_str_flex(&format!("{}0", max))
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+    check_invalid("2147483648000000000", RatioErrorKind::Overflow);
+    check_invalid("1_000_000_000_000_000_000", RatioErrorKind::Overflow);
+    assert_eq!(
+        *Ratio::<i16>::from_str_flex("1234567890123456")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_all_errors() {
+    let errors = |s: &str| {
+        from_str_flex_all_errors::<i32>(s)
+            .unwrap_err()
+            .iter()
+            .map(|e| (*e.kind(), e.overflow_source()))
+            .collect::<Vec<_>>()
+    };
+    use OverflowSource::*;
+    use RatioErrorKind::*;
+
+    assert_eq!(
+        errors("99999999999/0"),
+        [(Overflow, Some(Numerator)), (ZeroDenominator, None)]
+    );
+    assert_eq!(
+        errors("-99999999999 / 99999999999"),
+        [(Overflow, Some(Numerator)), (Overflow, Some(Denominator))]
+    );
+    assert_eq!(errors("1/99999999999"), [(Overflow, Some(Denominator))]);
+    assert_eq!(
+        errors("99999999999.5e"),
+        [(Overflow, Some(Numerator)), (DanglingExponent, None)]
+    );
+    assert_eq!(errors(" 1.5e- "), [(DanglingExponent, None)]);
+    assert_eq!(
+        errors("99999999999e99999999999"),
+        [(Overflow, Some(Numerator)), (ParseError, None)]
+    );
+    assert_eq!(
+        errors("-9_999_999_999.5E-1_000_000_000_000"),
+        [(Overflow, Some(Numerator)), (ParseError, None)]
+    );
+    assert_eq!(errors("1.5e99999999999"), [(ParseError, None)]);
+    // A valid exponent is checked together with its mantissa.
+    assert_eq!(errors("10000000000e-20"), [(Overflow, Some(Denominator))]);
+    assert_eq!(errors("99999999999e2"), [(Overflow, Some(Numerator))]);
+    assert_eq!(errors("1e-99"), [(Overflow, Some(Denominator))]);
+    assert_eq!(errors("x/0"), [(ParseError, None)]);
+    assert_eq!(errors("/0"), [(ParseError, None)]);
+    assert_eq!(errors("3/٠"), [(NonAsciiDigit { position: 2 }, None)]);
+
+    for s in ["3/4", "-1.5e2", " 0013/002 ", ".5"] {
+        assert_eq!(
+            from_str_flex_all_errors::<i32>(s),
+            Rational32::from_str_flex(s).map_err(|e| vec![e])
+        );
+    }
+}
+
+#[test]
+fn test_lenient_fraction() {
+    let strict = RationalParser::new();
+    let lenient = RationalParser::new().lenient_fraction(true);
+    let signed = RationalParser::new().allow_signed_denominator(true);
+    let both = lenient.clone().allow_signed_denominator(true);
+
+    for numer_sign in ["", "+", "-"] {
+        for numer_space in ["", " "] {
+            for denom_sign in ["", "+", "-"] {
+                for denom_space in ["", " "] {
+                    for padding in ["", " "] {
+                        if numer_sign.is_empty() && !numer_space.is_empty()
+                            || denom_sign.is_empty() && !denom_space.is_empty()
+                        {
+                            continue;
+                        }
+                        let s = format!(
+                            "{p}{}{}3{p}/{p}{}{}2{p}",
+                            numer_sign,
+                            numer_space,
+                            denom_sign,
+                            denom_space,
+                            p = padding
+                        );
+                        let negative = (numer_sign == "-") != (denom_sign == "-");
+                        let expected = Ratio::new(if negative { -3 } else { 3 }, 2);
+                        let sign_space = !numer_space.is_empty() || !denom_space.is_empty();
+                        let denom_signed = !denom_sign.is_empty();
+
+                        for (parser, ok) in [
+                            (&strict, !sign_space && !denom_signed),
+                            (&lenient, !denom_signed),
+                            (&signed, !sign_space),
+                            (&both, true),
+                        ] {
+                            let result = parser.parse::<i32>(&s);
+                            if ok {
+                                assert_eq!(result.unwrap(), expected, "input {:?}", s);
+                            } else {
+                                assert_eq!(
+                                    *result.unwrap_err().kind(),
+                                    RatioErrorKind::ParseError,
+                                    "input {:?}",
+                                    s
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        lenient.parse::<i32>("-\t1.5e1").unwrap(),
+        Ratio::new(-15, 1)
+    );
+    assert_eq!(lenient.parse::<i32>(" + .5 ").unwrap(), Ratio::new(1, 2));
+    for s in ["1e- 3", "- - 3", "3 - 2", "-", "- ", "3/ -", "1 2"] {
+        assert_eq!(
+            *both.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_max_significant_digits() {
+    let error = RationalParser::new().max_significant_digits(3, ExcessDigits::Error);
+    let round = RationalParser::new().max_significant_digits(3, ExcessDigits::Round);
+
+    for s in [
+        "123", "1.23", "0.00123", "1_2_3e5", "1200", "0.00120", "-999", "1/123456",
+    ] {
+        assert_eq!(
+            error.parse::<i32>(s),
+            Rational32::from_str_flex(s),
+            "input {:?}",
+            s
+        );
+        assert_eq!(
+            round.parse::<i32>(s),
+            Rational32::from_str_flex(s),
+            "input {:?}",
+            s
+        );
+    }
+    for s in ["1234", "100.5", "0.001234", "1.001e2", "12_34"] {
+        assert_eq!(
+            *error.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::TooManyDigits,
+            "input {:?}",
+            s
+        );
+    }
+
+    let rounded = |s: &str| round.parse::<i64>(s).unwrap();
+    assert_eq!(rounded("3.14159"), Ratio::new(157, 50));
+    assert_eq!(rounded("-3.14159"), Ratio::new(-157, 50));
+    assert_eq!(rounded("1234"), Ratio::new(1230, 1));
+    assert_eq!(rounded("1236"), Ratio::new(1240, 1));
+    assert_eq!(rounded("0.0012345e3"), Ratio::new(123, 100));
+    assert_eq!(rounded("9996"), Ratio::new(10000, 1));
+    assert_eq!(rounded("99.96"), Ratio::new(100, 1));
+    // Ties round to even.
+    assert_eq!(rounded("1225"), Ratio::new(1220, 1));
+    assert_eq!(rounded("1235"), Ratio::new(1240, 1));
+    assert_eq!(rounded("12.25000"), Ratio::new(61, 5));
+    assert_eq!(rounded("1.2250001"), Ratio::new(123, 100));
+
+    // Excess digits are dropped before they can overflow.
+    assert_eq!(
+        round
+            .parse::<i32>("1.23456789012345678901234567890")
+            .unwrap(),
+        Ratio::new(123, 100)
+    );
+    check_invalid("1.23456789012345678901234567890", RatioErrorKind::Overflow);
+}
+
+#[test]
+#[should_panic(expected = "significant digit limit must be positive")]
+fn test_max_significant_digits_zero() {
+    let _ = RationalParser::new().max_significant_digits(0, ExcessDigits::Round);
+}
+
+#[test]
+fn test_egyptian_fraction() {
+    let egyptian = |n: i64, d: i64| to_egyptian_fraction(&Ratio::new(n, d)).unwrap();
+
+    assert_eq!(egyptian(3, 4), [2, 4]);
+    assert_eq!(egyptian(1, 7), [7]);
+    assert_eq!(egyptian(2, 3), [2, 6]);
+    assert_eq!(egyptian(6, 7), [2, 3, 42]);
+    assert_eq!(egyptian(4, 13), [4, 18, 468]);
+    assert_eq!(egyptian(0, 1), Vec::<i64>::new());
+    for (n, d) in [(5, 6), (7, 15), (99, 100), (2, 2023)] {
+        let sum: Ratio<i64> = egyptian(n, d).into_iter().map(|u| Ratio::new(1, u)).sum();
+        assert_eq!(sum, Ratio::new(n, d));
+    }
+
+    let kind = |n: i64, d: i64| *to_egyptian_fraction(&Ratio::new(n, d)).unwrap_err().kind();
+    assert_eq!(kind(1, 1), RatioErrorKind::ImproperFraction);
+    assert_eq!(kind(5, 4), RatioErrorKind::ImproperFraction);
+    assert_eq!(kind(-5, 4), RatioErrorKind::ImproperFraction);
+    assert_eq!(kind(-1, 2), RatioErrorKind::OutOfRange);
+    assert_eq!(kind(5, 121), RatioErrorKind::Overflow);
+    assert_eq!(
+        to_egyptian_fraction(&Ratio::<num_bigint::BigInt>::new(5.into(), 121.into()))
+            .unwrap()
+            .len(),
+        5
+    );
+}
+
+#[test]
+fn test_flex_ratio() {
+    let r: FlexRatio<i32> = (3, 4).try_into().unwrap();
+    assert_eq!(r, FlexRatio(Ratio::new(3, 4)));
+    assert_eq!(
+        Ratio::from(FlexRatio::try_from((6i64, -4)).unwrap()),
+        Ratio::new(-3, 2)
+    );
+    let err = FlexRatio::<i32>::try_from((3, 0)).unwrap_err();
+    assert_eq!(err, ParseRatioError::from(RatioErrorKind::ZeroDenominator));
+    assert_eq!(err.overflow_source(), None);
+    let err = FlexRatio::<i32>::try_from((i32::MIN, -1)).unwrap_err();
+    assert_eq!(*err.kind(), RatioErrorKind::Overflow);
+    let err: Result<FlexRatio<i8>, _> = (1, i8::MIN).try_into();
+    assert_eq!(err, Err(RatioErrorKind::Overflow.into()));
+    assert_eq!(
+        FlexRatio::<i8>::try_from((i8::MIN, -2)).unwrap(),
+        FlexRatio(Ratio::from_integer(64))
+    );
+
+    assert_eq!(
+        "1_000/-2e0".parse::<FlexRatio<i32>>(),
+        Err(RatioErrorKind::ParseError.into())
+    );
+    assert_eq!(
+        "-1_000/2_000"
+            .parse::<FlexRatio<i32>>()
+            .unwrap()
+            .into_inner(),
+        Ratio::new(-1, 2)
+    );
+    assert_eq!(
+        "1/0".parse::<FlexRatio<i32>>(),
+        Err(RatioErrorKind::ZeroDenominator.into())
+    );
+}
+
+#[test]
+fn test_parenthesized_negative() {
+    let parser = RationalParser::new().allow_parenthesized_negative(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+    let kind = |s: &str| *parser.parse::<i32>(s).unwrap_err().kind();
+
+    assert_eq!(parse("(3/4)").unwrap(), Ratio::new(-3, 4));
+    assert_eq!(parse(" ( 1.5 ) ").unwrap(), Ratio::new(-3, 2));
+    assert_eq!(parse("(1e2)").unwrap(), Ratio::new(-100, 1));
+    assert_eq!(parse("(0)").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parse("3/4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(parse("-3/4").unwrap(), Ratio::new(-3, 4));
+
+    for s in [
+        "(-3/4)", "(+3/4)", "(3/4", "3/4)", "((3/4))", "()", "(3)/4", "-(3/4)",
+    ] {
+        assert_eq!(kind(s), RatioErrorKind::ParseError, "input {:?}", s);
+    }
+
+    assert_eq!(
+        parser
+            .clone()
+            .require_sign(true)
+            .parse::<i32>("(3/4)")
+            .unwrap(),
+        Ratio::new(-3, 4)
+    );
+    assert_eq!(
+        parser
+            .clone()
+            .allow_percent(true)
+            .parse::<i32>("(50%)")
+            .unwrap(),
+        Ratio::new(-1, 2)
+    );
+
+    // Error positions refer to the input as given, before the rewrite.
+    for (s, position) in [("   (٣)", 4), ("(1/ ٣) ", 4), (" ( 12٣ )", 5)] {
+        assert_eq!(
+            *parser.parse::<i32>(s).unwrap_err().kind(),
+            RatioErrorKind::NonAsciiDigit { position },
+            "input {:?}",
+            s
+        );
+    }
+
+    check_invalid("(3/4)", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_from_str_flex_canonical() {
+    let canonical = |s: &str| from_str_flex_canonical::<i64>(s).unwrap().1;
+
+    assert_eq!(canonical("3"), "3");
+    assert_eq!(canonical("6/2"), "3");
+    assert_eq!(canonical("3/1"), "3");
+    assert_eq!(canonical("-0.0"), "0");
+    assert_eq!(canonical("2.50e1"), "25");
+    assert_eq!(canonical("3.14"), "157/50");
+    assert_eq!(canonical(" -1_000/2_000 "), "-1/2");
+    assert_eq!(canonical("1e-3"), "1/1000");
+    for s in ["0.75", "3/4", "75e-2", "6/8"] {
+        let (value, text) = from_str_flex_canonical::<i64>(s).unwrap();
+        assert_eq!(value, Ratio::new(3, 4));
+        assert_eq!(text, "3/4");
+        assert_eq!(Ratio::<i64>::from_str_flex(&text).unwrap(), value);
+    }
+
+    assert_eq!(
+        *from_str_flex_canonical::<i32>("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_preserve_trailing_zeros() {
+    let parser = RationalParser::new().preserve_trailing_zeros(true);
+    let raw = |s: &str| parser.parse::<i64>(s).unwrap().into_raw();
+
+    assert_eq!(raw("1.50"), (150, 100));
+    assert_eq!(raw("1.5"), (15, 10));
+    assert_eq!(raw("-1.500"), (-1500, 1000));
+    // Zero has no scale.
+    assert_eq!(raw("0.00"), (0, 1));
+    assert_eq!(raw("1200"), (1200, 1));
+    assert_eq!(raw("1200e-2"), (1200, 100));
+    assert_eq!(raw("1.50e1"), (150, 10));
+    assert_eq!(raw("1.50e-1"), (150, 1000));
+    assert_eq!(raw("1.5e3"), (1500, 1));
+    assert_eq!(raw("1_0.0_0"), (1000, 100));
+    // Fractions are still reduced.
+    assert_eq!(raw("6/8"), (3, 4));
+
+    // Unreduced values compare equal to their reduced form.
+    assert_eq!(parser.parse::<i64>("1.50").unwrap(), Ratio::new(3, 2));
+    assert_eq!(
+        RationalParser::new()
+            .parse::<i64>("1.50")
+            .unwrap()
+            .into_raw(),
+        (3, 2)
+    );
+
+    assert_eq!(
+        *parser.parse::<i32>("1.0000000000").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        RationalParser::new().parse::<i32>("1.0000000000").unwrap(),
+        Ratio::new(1, 1)
+    );
+
+    // Trailing zeros count as significant digits.
+    let limited = parser.max_significant_digits(2, ExcessDigits::Error);
+    assert_eq!(
+        *limited.parse::<i64>("1.50").unwrap_err().kind(),
+        RatioErrorKind::TooManyDigits
+    );
+}
+
+#[test]
+fn test_parse_flex() {
+    for s in ["3/4", "-1.5e-2", " 1_000 ", "0x1", "1/0", ""] {
+        assert_eq!(
+            num_rational_parse::parse_flex::<i32>(s),
+            Rational32::from_str_flex(s),
+            "input {:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_words() {
+    let parser = RationalParser::new().allow_words(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    for (s, expected) in [
+        ("3 per 4", Ratio::new(3, 4)),
+        ("3per4", Ratio::new(3, 4)),
+        (" -6 PER 8 ", Ratio::new(-3, 4)),
+        ("1_5 per 3_0", Ratio::new(1, 2)),
+        ("5 per cent", Ratio::new(1, 20)),
+        ("5 percent", Ratio::new(1, 20)),
+        ("12.5 PerCent ", Ratio::new(1, 8)),
+        ("5per  cent", Ratio::new(1, 20)),
+        ("3 per 4 percent", Ratio::new(3, 400)),
+        ("3/4", Ratio::new(3, 4)),
+    ] {
+        assert_eq!(parse(s).unwrap(), expected, "input {:?}", s);
+    }
+
+    for s in [
+        "per 4",
+        "3 per",
+        "per",
+        "percent",
+        "5 percent percent",
+        "5 cent",
+        "3 pr 4",
+        "3 per per 4",
+        "5 per cent 2",
+        "5%",
+    ] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        parser
+            .clone()
+            .allow_percent(true)
+            .parse::<i32>("5%")
+            .unwrap(),
+        Ratio::new(1, 20)
+    );
+    assert_eq!(
+        *parse("3 per 0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        *parse("1.5 per 3").unwrap_err().kind(),
+        RatioErrorKind::MixedFractionDecimal
+    );
+
+    // Error positions refer to the input before `per` is rewritten.
+    for (s, position) in [("3 per ٣", 6), ("3 PER 4 per cent٣", 16), ("1 and ٣/4", 6)] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::NonAsciiDigit { position },
+            "input {:?}",
+            s
+        );
+    }
+
+    // Words are rejected by default.
+    for s in ["3 per 4", "5 percent"] {
+        assert_eq!(
+            *Rational32::from_str_flex(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError
+        );
+    }
+}
+
+#[test]
+fn test_words_mixed_number() {
+    let parser = RationalParser::new().allow_words(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    for (s, expected) in [
+        ("1 and 3/4", Ratio::new(7, 4)),
+        (" 2 AND 1 / 2 ", Ratio::new(5, 2)),
+        ("1 and 3 per 4", Ratio::new(7, 4)),
+        ("-1 and 3/4", Ratio::new(-7, 4)),
+        ("-0 and 1/2", Ratio::new(-1, 2)),
+        ("+3 and 0/5", Ratio::from_integer(3)),
+        ("1_000 and 1/1_000", Ratio::new(1000001, 1000)),
+        ("0 and 2/4", Ratio::new(1, 2)),
+    ] {
+        assert_eq!(parse(s).unwrap(), expected, "input {:?}", s);
+    }
+    assert_eq!(
+        parser
+            .clone()
+            .allow_percent(true)
+            .parse::<i32>("1 and 1/2 %")
+            .unwrap(),
+        Ratio::new(3, 200)
+    );
+    assert_eq!(parse("1 and 1/2 percent").unwrap(), Ratio::new(3, 200));
+    assert_eq!(
+        parser
+            .clone()
+            .allow_parenthesized_negative(true)
+            .parse::<i32>("(1 and 1/4)")
+            .unwrap(),
+        Ratio::new(-5, 4)
+    );
+
+    for s in [
+        "1 and 5/4",
+        "1 and 4/4",
+        "1.5 and 1/2",
+        "1 and 0.5",
+        "1 and -1/2",
+        "1 and",
+        "and 1/2",
+        "1and 1/2",
+        "1 and 1/2 and 1/4",
+        "1 and 1",
+        "one and three quarters",
+    ] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    assert_eq!(
+        *parse("1 and 1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        *parser.parse::<i8>("127 and 1/2").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        parser.parse::<i8>("-63 and 1/2").unwrap(),
+        Ratio::new(-127, 2)
+    );
+
+    // The sign requirement applies to the whole part only.
+    let signed = parser.clone().require_sign(true);
+    assert_eq!(signed.parse::<i32>("+1 and 1/2").unwrap(), Ratio::new(3, 2));
+    assert_eq!(
+        *signed.parse::<i32>("1 and 1/2").unwrap_err().kind(),
+        RatioErrorKind::MissingSign
+    );
+
+    // Without words, "and" is rejected.
+    assert_eq!(
+        *Rational32::from_str_flex("1 and 3/4").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+}
+
+#[test]
+fn test_division_sign() {
+    let parser = RationalParser::new().allow_division_sign(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    assert_eq!(parse("3÷4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(parse(" -3 ÷ 4 ").unwrap(), Ratio::new(-3, 4));
+    assert_eq!(parse("1_000÷2_000").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse("3/4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(
+        *parse("3÷0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+    assert_eq!(
+        *parse("1.5÷2").unwrap_err().kind(),
+        RatioErrorKind::MixedFractionDecimal
+    );
+    for s in ["3÷", "÷4", "3÷÷4", "3÷4÷5"] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::ParseError,
+            "input {:?}",
+            s
+        );
+    }
+    // Error positions count the two bytes of the `÷`.
+    for (s, position) in [("3÷٣", 3), ("12 ÷ 3٣", 7), ("٣÷4", 0)] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::NonAsciiDigit { position },
+            "input {:?}",
+            s
+        );
+    }
+
+    assert_eq!(
+        parser
+            .allow_chained_division(true)
+            .parse::<i32>("12÷3÷2")
+            .unwrap(),
+        Ratio::new(2, 1)
+    );
+
+    check_invalid("3÷4", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_i16_boundaries() {
+    type Rational16 = Ratio<i16>;
+    let parse = |s: &str| Rational16::from_str_flex(s);
+
+    assert_eq!(parse("32767").unwrap(), Rational16::from_integer(i16::MAX));
+    assert_eq!(
+        parse("-32767").unwrap(),
+        Rational16::from_integer(-i16::MAX)
+    );
+    assert_eq!(parse("-32768").unwrap(), Rational16::from_integer(i16::MIN));
+    assert_eq!(
+        parse("3.2767e4").unwrap(),
+        Rational16::from_integer(i16::MAX)
+    );
+    assert_eq!(parse("1/32767").unwrap(), Rational16::new(1, i16::MAX));
+    assert_eq!(parse("-32768/2").unwrap(), Rational16::from_integer(-16384));
+
+    for s in [
+        "32768", "-32769", "3.2768e4", "1/32768", "-1/32768", "32768/2",
+    ] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::Overflow,
+            "input {:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_most_negative_value() {
+    fn check<T: FlexInteger + std::fmt::Display + std::fmt::Debug + Copy>(min: T, max: T) {
+        let parse = |s: &str| Ratio::<T>::from_str_flex(s);
+        assert_eq!(parse(&min.to_string()).unwrap(), Ratio::from_integer(min));
+        assert_eq!(parse(&max.to_string()).unwrap(), Ratio::from_integer(max));
+        assert_eq!(
+            parse(&format!("{min}/1")).unwrap(),
+            Ratio::from_integer(min)
+        );
+        assert_eq!(
+            parse(&format!("{min}.0")).unwrap(),
+            Ratio::from_integer(min)
+        );
+        assert_eq!(
+            parse(&format!("{min}e0")).unwrap(),
+            Ratio::from_integer(min)
+        );
+        assert_eq!(
+            parse(&format!("{min}_0e-1")).unwrap(),
+            Ratio::from_integer(min)
+        );
+        // The magnitude of the minimum is only valid with a minus sign.
+        let magnitude = min.to_string()[1..].to_owned();
+        assert_eq!(
+            *parse(&magnitude).unwrap_err().kind(),
+            RatioErrorKind::Overflow
+        );
+        assert_eq!(
+            *parse(&format!("{min}/-1")).unwrap_err().kind(),
+            RatioErrorKind::ParseError
+        );
+        let signed = RationalParser::new().allow_signed_denominator(true);
+        assert_eq!(
+            signed.parse::<T>(&format!("{magnitude}/-1")).unwrap(),
+            Ratio::from_integer(min)
+        );
+        assert_eq!(
+            *signed.parse::<T>(&format!("{min}/-1")).unwrap_err().kind(),
+            RatioErrorKind::Overflow
+        );
+    }
+
+    check(i8::MIN, i8::MAX);
+    check(i16::MIN, i16::MAX);
+    check(i32::MIN, i32::MAX);
+    check(i64::MIN, i64::MAX);
+    check(i128::MIN, i128::MAX);
+    check(isize::MIN, isize::MAX);
+
+    let round = RationalParser::new().max_significant_digits(3, ExcessDigits::Round);
+    assert_eq!(
+        round.parse::<i8>("-127.5").unwrap(),
+        Ratio::from_integer(i8::MIN)
+    );
+    assert_eq!(
+        *round.parse::<i8>("127.5").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_overline_repetend() {
+    let parser = RationalParser::new().allow_overline_repetend(true);
+    let parse = |s: &str| parser.parse::<i64>(s);
+    let kind = |s: &str| *parser.parse::<i64>(s).unwrap_err().kind();
+
+    assert_eq!(parse("0.3\u{305}").unwrap(), Ratio::new(1, 3));
+    assert_eq!(parse("0.3\u{304}").unwrap(), Ratio::new(1, 3));
+    assert_eq!(parse("0.3‾").unwrap(), Ratio::new(1, 3));
+    assert_eq!(parse(".3\u{305}").unwrap(), Ratio::new(1, 3));
+    assert_eq!(parse(" -0.3\u{305} ").unwrap(), Ratio::new(-1, 3));
+    assert_eq!(parse("0.16\u{305}").unwrap(), Ratio::new(1, 6));
+    assert_eq!(parse("0.9\u{305}").unwrap(), Ratio::new(1, 1));
+    assert_eq!(
+        parse("1.1\u{305}4\u{305}2\u{305}8\u{305}5\u{305}7\u{305}").unwrap(),
+        Ratio::new(8, 7)
+    );
+    assert_eq!(
+        parse("-2.08\u{305}3\u{305}").unwrap(),
+        Ratio::new(-2063, 990)
+    );
+    assert_eq!(parse("1_0.1_2\u{305}").unwrap(), Ratio::new(911, 90));
+    // Without marks, decimals parse as usual.
+    assert_eq!(parse("0.3").unwrap(), Ratio::new(3, 10));
+
+    for s in [
+        "3\u{305}",
+        "3\u{305}.5",
+        "0.\u{305}",
+        "\u{305}0.3",
+        "0.3\u{305}3",
+        "0.3\u{305}\u{305}",
+        "0.3\u{305}4.5\u{305}",
+        "0.3\u{305}12\u{305}",
+        "0.3\u{305}e2",
+        "1/3\u{305}",
+        "0.3\u{305} 1",
+    ] {
+        assert_eq!(kind(s), RatioErrorKind::ParseError, "input {:?}", s);
+    }
+
+    assert_eq!(
+        *parser
+            .parse::<i8>("0.1\u{305}2\u{305}3\u{305}")
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::Overflow
+    );
+    check_invalid("0.3\u{305}", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_from_parse_int_error() {
+    let kind = |e: std::num::ParseIntError| *ParseRatioError::from(e).kind();
+
+    assert_eq!(
+        kind("128".parse::<i8>().unwrap_err()),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        kind("-129".parse::<i8>().unwrap_err()),
+        RatioErrorKind::Overflow
+    );
+    assert_eq!(
+        kind("".parse::<i8>().unwrap_err()),
+        RatioErrorKind::ParseError
+    );
+    assert_eq!(
+        kind("1.5".parse::<i8>().unwrap_err()),
+        RatioErrorKind::ParseError
+    );
+    assert_eq!(
+        kind("0".parse::<std::num::NonZeroU8>().unwrap_err()),
+        RatioErrorKind::ParseError
+    );
+
+    // `?` converts integer errors in composite parsers.
+    fn scaled(s: &str) -> Result<Rational32, ParseRatioError> {
+        let (value, scale) = s.split_once(';').ok_or(RatioErrorKind::ParseError)?;
+        Ok(Rational32::from_str_flex(value)? * scale.parse::<i32>()?)
+    }
+    assert_eq!(scaled("1/2;4").unwrap(), Ratio::new(2, 1));
+    assert_eq!(
+        *scaled("1/2;x").unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+    assert_eq!(
+        *scaled("1/2;99999999999").unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_forbid_leading_zeros() {
+    let parser = RationalParser::new().forbid_leading_zeros(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+
+    assert_eq!(parse("0").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parse("-0").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parse("0.5").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse("0.05").unwrap(), Ratio::new(1, 20));
+    assert_eq!(parse(".05").unwrap(), Ratio::new(1, 20));
+    assert_eq!(parse("10/20").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse("0/10").unwrap(), Ratio::new(0, 1));
+    assert_eq!(parse("1e0").unwrap(), Ratio::new(1, 1));
+    assert_eq!(
+        parse("1e-10_0").unwrap_err().kind(),
+        &RatioErrorKind::Overflow
+    );
+    assert_eq!(parse("1_000").unwrap(), Ratio::new(1000, 1));
+
+    for s in [
+        "007", "-07", "00", "00.5", "0_1", "0013/002", "13/02", "1/00", "1e05", "1e-05", "1e+00",
+    ] {
+        assert_eq!(
+            *parse(s).unwrap_err().kind(),
+            RatioErrorKind::LeadingZero,
+            "input {:?}",
+            s
+        );
+    }
+
+    assert_eq!(components("0013/002"), (13, 2));
+    assert_eq!(components("1e05"), (100000, 1));
+}
+
+#[test]
+fn test_from_str_flex_with_gcd() {
+    let with_gcd = |s: &str| from_str_flex_with_gcd::<i32>(s).unwrap();
+
+    assert_eq!(with_gcd("6/4"), (Ratio::new(3, 2), 2));
+    assert_eq!(with_gcd("3/4"), (Ratio::new(3, 4), 1));
+    assert_eq!(with_gcd("-12/18"), (Ratio::new(-2, 3), 6));
+    assert_eq!(with_gcd("0/5"), (Ratio::new(0, 1), 5));
+    assert_eq!(with_gcd("7"), (Ratio::new(7, 1), 1));
+    assert_eq!(with_gcd("0.5"), (Ratio::new(1, 2), 5));
+    assert_eq!(with_gcd("1.50"), (Ratio::new(3, 2), 5));
+    assert_eq!(with_gcd("-2.5e-1"), (Ratio::new(-1, 4), 25));
+    assert_eq!(with_gcd("-2147483648/2"), (Ratio::new(-1073741824, 1), 2));
+
+    for s in ["6/4", "0.125", "1_000/2_000", "-3.5e2", "1/3"] {
+        assert_eq!(
+            from_str_flex_with_gcd::<i32>(s).unwrap().0,
+            Rational32::from_str_flex(s).unwrap()
+        );
+    }
+    assert_eq!(
+        *from_str_flex_with_gcd::<i32>("1/0").unwrap_err().kind(),
+        RatioErrorKind::ZeroDenominator
+    );
+}
+
+#[test]
+fn test_exponent_leading_zeros() {
+    let parse = |s: &str| Rational64::from_str_flex(s).unwrap();
+
+    assert_eq!(parse("1e05"), Ratio::new(100_000, 1));
+    assert_eq!(parse("1E+06"), Ratio::new(1_000_000, 1));
+    assert_eq!(parse("1e007"), Ratio::new(10_000_000, 1));
+    assert_eq!(parse("1e-007"), Ratio::new(1, 10_000_000));
+    assert_eq!(parse("1e00"), Ratio::new(1, 1));
+    assert_eq!(parse("1e-00"), Ratio::new(1, 1));
+    assert_eq!(parse("2.5e0_1"), Ratio::new(25, 1));
+    // Only the value of the exponent matters, not its length.
+    assert_eq!(parse("1e000000000010"), Ratio::new(10_000_000_000, 1));
+    assert_eq!(parse("1e-000000000010"), Ratio::new(1, 10_000_000_000));
+    assert_eq!(
+        parse("1e0000000000000000000000000000000000000000000000000018"),
+        Ratio::new(10i64.pow(18), 1)
+    );
+
+    check_invalid("1e000000000010", RatioErrorKind::Overflow);
+}
+
+#[test]
+fn test_from_str_flex_or_else() {
+    let vulgar = |s: &str| match s.trim() {
+        "½" => Ok(Ratio::new(1, 2)),
+        "¼" => Ok(Ratio::new(1, 4)),
+        _ => Err(ParseRatioError::from(RatioErrorKind::OutOfRange)),
+    };
+    let parse = |s: &str| from_str_flex_or_else::<i32>(s, vulgar);
+
+    assert_eq!(parse(" ½ ").unwrap(), Ratio::new(1, 2));
+    assert_eq!(parse("¼").unwrap(), Ratio::new(1, 4));
+    assert_eq!(parse("3/4").unwrap(), Ratio::new(3, 4));
+    assert_eq!(*parse("x").unwrap_err().kind(), RatioErrorKind::OutOfRange);
+    assert_eq!(*parse("1e").unwrap_err().kind(), RatioErrorKind::OutOfRange);
+    assert_eq!(*parse("١").unwrap_err().kind(), RatioErrorKind::OutOfRange);
+
+    // Errors of well-formed numbers are not passed on.
+    let unreachable =
+        |s: &str| -> Result<Rational32, ParseRatioError> { panic!("fallback called for {:?}", s) };
+    for (s, kind) in [
+        ("1/0", RatioErrorKind::ZeroDenominator),
+        ("2147483648", RatioErrorKind::Overflow),
+    ] {
+        assert_eq!(
+            *from_str_flex_or_else(s, unreachable).unwrap_err().kind(),
+            kind
+        );
+    }
+}
+
+#[test]
+fn test_fraction_exponent() {
+    let parser = RationalParser::new().allow_fraction_exponent(true);
+    let parse = |s: &str| parser.parse::<i32>(s);
+    let kind = |s: &str| *parser.parse::<i32>(s).unwrap_err().kind();
+
+    for (s, numer, denom) in [
+        ("3/4e2", 75, 1),
+        ("3/4E2", 75, 1),
+        ("3/4e+2", 75, 1),
+        ("3/4e-1", 3, 40),
+        ("3/4e0", 3, 4),
+        ("-3/4e1", -15, 2),
+        ("1/10e1", 1, 1),
+        ("1/10e-1", 1, 100),
+        ("100/3e-2", 1, 3),
+        ("0/5e99", 0, 1),
+        ("1_0/4e0_1", 25, 1),
+        (" 3 / 4e2 ", 75, 1),
+        // Powers of ten cancel before the other side is scaled.
+        ("5/10e9", 500_000_000, 1),
+        ("1/1000000000e9", 1, 1),
+        ("1000000000/7e-9", 1, 7),
+    ] {
+        assert_eq!(parse(s).unwrap(), Ratio::new(numer, denom), "input {:?}", s);
+    }
+
+    for s in ["1/2e10", "3/7e10", "1/2e-10", "2147483647/1e1"] {
+        assert_eq!(kind(s), RatioErrorKind::Overflow, "input {:?}", s);
+    }
+    assert_eq!(kind("3/4e"), RatioErrorKind::DanglingExponent);
+    assert_eq!(kind("3/0e2"), RatioErrorKind::ZeroDenominator);
+    for s in ["3/4.5e2", "3e2/4", "3/4e2e2", "3/4 e2", "3/4p2"] {
+        assert_eq!(kind(s), RatioErrorKind::ParseError, "input {:?}", s);
+    }
+
+    let binary = parser.clone().allow_binary_exponent(true);
+    assert_eq!(binary.parse::<i32>("3/4p2").unwrap(), Ratio::new(3, 1));
+    assert_eq!(binary.parse::<i32>("3/4p-1").unwrap(), Ratio::new(3, 8));
+    assert_eq!(binary.parse::<i32>("3/8p3").unwrap(), Ratio::new(3, 1));
+
+    // Exponents on fractions are rejected by default.
+    check_invalid("3/4e2", RatioErrorKind::ParseError);
+    check_invalid("3/4e", RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_from_str_flex_as_int() {
+    assert_eq!(from_str_flex_as_int::<i32>("3").unwrap(), 3);
+    assert_eq!(from_str_flex_as_int::<i32>("6/2").unwrap(), 3);
+    assert_eq!(from_str_flex_as_int::<i32>("3e2").unwrap(), 300);
+    assert_eq!(from_str_flex_as_int::<i32>("-2.50e1").unwrap(), -25);
+    assert_eq!(from_str_flex_as_int::<i32>("-0").unwrap(), 0);
+    assert_eq!(from_str_flex_as_int::<i8>("-128").unwrap(), i8::MIN);
+    assert_eq!(
+        from_str_flex_as_int::<i64>("9.223372036854775807e18").unwrap(),
+        i64::MAX
+    );
+
+    let kind = |s: &str| *from_str_flex_as_int::<i32>(s).unwrap_err().kind();
+    assert_eq!(kind("3/2"), RatioErrorKind::NonInteger);
+    assert_eq!(kind("0.5"), RatioErrorKind::NonInteger);
+    assert_eq!(kind("2147483648"), RatioErrorKind::Overflow);
+    assert_eq!(kind("1/0"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind("x"), RatioErrorKind::ParseError);
+}
+
+#[test]
+fn test_mixed_fraction_decimal() {
+    for s in [
+        "3.2/7",
+        "3/7.2",
+        "3.2/7.1",
+        "-3.2/7",
+        ".5/2",
+        "5./2",
+        "1/.5",
+        " 1_0.5 / 2 ",
+    ] {
+        check_invalid(s, RatioErrorKind::MixedFractionDecimal);
+    }
+    // Other malformed fractions remain plain parse errors.
+    for s in [
+        "3..2/7", "3.2/7/1", "3.2e1/7", "3/7.2e1", "3.2/", "/7.2", "3.2/x",
+    ] {
+        check_invalid(s, RatioErrorKind::ParseError);
+    }
+    assert_eq!(
+        RatioErrorKind::MixedFractionDecimal.to_string(),
+        "decimal point in a fraction"
+    );
+}
@@ -0,0 +1,160 @@
+use num_rational::{Ratio, Rational32};
+use num_rational_parse::{
+    FeedResult, PartialParser, RatioErrorKind, RationalParse, from_chars_flex,
+};
+
+fn feed_all(s: &str) -> (PartialParser, Vec<FeedResult>) {
+    let mut parser = PartialParser::new();
+    let results = s.chars().map(|c| parser.feed(c)).collect();
+    (parser, results)
+}
+
+#[test]
+fn test_feed() {
+    use FeedResult::*;
+
+    let (parser, results) = feed_all(" -1_2.5e+3 ");
+    assert_eq!(
+        results,
+        [
+            Accept, Accept, Complete, Accept, Complete, Complete, Complete, Accept, Accept,
+            Complete, Complete
+        ]
+    );
+    assert_eq!(parser.finish::<i32>().unwrap(), Ratio::new(-12500, 1));
+
+    let (parser, results) = feed_all("3 / 4");
+    assert_eq!(results, [Complete, Complete, Accept, Accept, Complete]);
+    assert_eq!(parser.finish::<i32>().unwrap(), Ratio::new(3, 4));
+
+    let (parser, results) = feed_all(".");
+    assert_eq!(results, [Accept]);
+    assert!(!parser.is_complete());
+    assert_eq!(
+        *parser.finish::<i32>().unwrap_err().kind(),
+        RatioErrorKind::ParseError
+    );
+}
+
+#[test]
+fn test_reject_keeps_state() {
+    let mut parser = PartialParser::new();
+    assert_eq!(parser.feed('1'), FeedResult::Complete);
+    assert_eq!(parser.feed('_'), FeedResult::Accept);
+    assert_eq!(parser.feed('_'), FeedResult::Reject);
+    assert_eq!(parser.feed('/'), FeedResult::Reject);
+    assert_eq!(parser.feed('2'), FeedResult::Complete);
+    assert_eq!(parser.feed('x'), FeedResult::Reject);
+    assert_eq!(parser.as_str(), "1_2");
+    assert_eq!(parser.finish::<i32>().unwrap(), Ratio::new(12, 1));
+}
+
+#[test]
+fn test_matches_from_str_flex() {
+    let inputs = [
+        "314",
+        "-35/4",
+        "3.1415",
+        "-47e-2",
+        "1_000/1",
+        "1.50_0",
+        " \n  +3/2",
+        "-3/2  ",
+        "    0013/002 \n  ",
+        " .6 ",
+        " -3. ",
+        "32.e-5",
+        "1E+06",
+        " .0e+0\t",
+        "1/0",
+        "3/",
+        "/2",
+        "3/+2",
+        "+ 3/2",
+        "3a2",
+        "3/7.2",
+        "3.2/7",
+        "3 .2",
+        "3. 2",
+        "3.2 e1",
+        "3.2e 1",
+        "3.+2",
+        "0x10",
+        "1.0e0x10",
+        "³",
+        "3.²",
+        ".",
+        "_",
+        "_1",
+        "1__2",
+        "1/_1",
+        "1._111",
+        "1.1e+_1",
+        "1.1e+1__1",
+        "123.5_dd",
+        "789e2_dd",
+        "3e",
+        "3e+",
+        "-",
+        "+.",
+        "-.5",
+        "3 e1",
+        "3 /  4 ",
+        "\u{a0}3/2\u{3000}",
+        "٣",
+        "1/٢",
+        "1x٢",
+    ];
+    for s in inputs {
+        let (parser, results) = feed_all(s);
+        let fully_accepted = !results.contains(&FeedResult::Reject);
+        let complete = fully_accepted && parser.is_complete();
+        let parsed = Rational32::from_str_flex(s);
+        let syntax_ok = !matches!(
+            parsed.map_err(|e| *e.kind()),
+            Err(RatioErrorKind::ParseError
+                | RatioErrorKind::DanglingExponent
+                | RatioErrorKind::MixedFractionDecimal
+                | RatioErrorKind::NonAsciiDigit { .. })
+        );
+        assert_eq!(complete, syntax_ok, "input {:?}", s);
+        if complete {
+            assert_eq!(parser.finish::<i32>(), parsed, "input {:?}", s);
+        }
+        assert_eq!(
+            from_chars_flex::<i32, _>(s.chars()),
+            parsed,
+            "input {:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_from_chars() {
+    let mut chars = "12/34 rest".chars();
+    let number = chars.by_ref().take(5);
+    assert_eq!(
+        from_chars_flex::<i32, _>(number).unwrap(),
+        Ratio::new(6, 17)
+    );
+    assert_eq!(chars.as_str(), " rest");
+
+    assert_eq!(
+        *from_chars_flex::<i32, _>("1x234".chars())
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::ParseError
+    );
+    assert_eq!(
+        *from_chars_flex::<i32, _>("1x2٣".chars())
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::NonAsciiDigit { position: 3 }
+    );
+
+    assert_eq!(
+        *from_chars_flex::<i8, _>("1000".chars()).unwrap_err().kind(),
+        RatioErrorKind::Overflow
+    );
+}
@@ -0,0 +1,57 @@
+use num_rational::Ratio;
+use num_rational_parse::{RatioErrorKind, parse_range, parse_range_with};
+
+#[test]
+fn test_range() {
+    let range = |s: &str| parse_range::<i32>(s).unwrap();
+
+    assert_eq!(range("1/2..3/4"), (Ratio::new(1, 2), Ratio::new(3, 4)));
+    assert_eq!(
+        range(" -1.5e1 .. 2 "),
+        (Ratio::new(-15, 1), Ratio::new(2, 1))
+    );
+    assert_eq!(range("1/3..1/3"), (Ratio::new(1, 3), Ratio::new(1, 3)));
+    assert_eq!(range("0...5"), (Ratio::new(0, 1), Ratio::new(1, 2)));
+    assert_eq!(range("-2..-1"), (Ratio::new(-2, 1), Ratio::new(-1, 1)));
+}
+
+#[test]
+fn test_range_invalid() {
+    let kind = |s: &str| *parse_range::<i32>(s).unwrap_err().kind();
+
+    assert_eq!(kind("1/2"), RatioErrorKind::ParseError);
+    assert_eq!(kind("..1"), RatioErrorKind::ParseError);
+    assert_eq!(kind("1.."), RatioErrorKind::ParseError);
+    assert_eq!(kind("1..2..3"), RatioErrorKind::ParseError);
+    assert_eq!(kind("1/0..2"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind("1..3e"), RatioErrorKind::DanglingExponent);
+    assert_eq!(kind("0..1e10"), RatioErrorKind::Overflow);
+    assert_eq!(kind("3/4..1/2"), RatioErrorKind::InvalidRange);
+    assert_eq!(kind("0..-0.001"), RatioErrorKind::InvalidRange);
+}
+
+#[test]
+fn test_range_with() {
+    assert_eq!(
+        parse_range_with::<i32>("1/2;1/4", ";", false).unwrap(),
+        (Ratio::new(1, 2), Ratio::new(1, 4))
+    );
+    assert_eq!(
+        *parse_range_with::<i32>("1/2;1/4", ";", true)
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::InvalidRange
+    );
+    assert_eq!(
+        *parse_range_with::<i32>("1/2..1/4", ";", false)
+            .unwrap_err()
+            .kind(),
+        RatioErrorKind::ParseError
+    );
+}
+
+#[test]
+#[should_panic(expected = "empty range separator")]
+fn test_range_empty_separator() {
+    let _ = parse_range_with::<i32>("1..2", "", true);
+}
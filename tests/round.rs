@@ -0,0 +1,59 @@
+use num_rational::Ratio;
+use num_rational_parse::{RatioErrorKind, round_to_decimals, round_to_decimals_half_up};
+
+#[test]
+fn test_round_half_even() {
+    let round = |n: i32, d: i32, places: u32| round_to_decimals(&Ratio::new(n, d), places).unwrap();
+
+    assert_eq!(round(1, 3, 2), Ratio::new(33, 100));
+    assert_eq!(round(2, 3, 2), Ratio::new(67, 100));
+    assert_eq!(round(-2, 3, 2), Ratio::new(-67, 100));
+    assert_eq!(round(1, 3, 0), Ratio::new(0, 1));
+    assert_eq!(round(3, 4, 5), Ratio::new(3, 4));
+    assert_eq!(round(7, 1, 3), Ratio::new(7, 1));
+
+    // Ties go to the even neighbor.
+    assert_eq!(round(1, 2, 0), Ratio::new(0, 1));
+    assert_eq!(round(3, 2, 0), Ratio::new(2, 1));
+    assert_eq!(round(5, 2, 0), Ratio::new(2, 1));
+    assert_eq!(round(-5, 2, 0), Ratio::new(-2, 1));
+    assert_eq!(round(-7, 2, 0), Ratio::new(-4, 1));
+    assert_eq!(round(1, 8, 2), Ratio::new(3, 25));
+    assert_eq!(round(3, 8, 2), Ratio::new(19, 50));
+    assert_eq!(round(-1, 8, 2), Ratio::new(-3, 25));
+}
+
+#[test]
+fn test_round_half_up() {
+    let round =
+        |n: i32, d: i32, places: u32| round_to_decimals_half_up(&Ratio::new(n, d), places).unwrap();
+
+    assert_eq!(round(1, 3, 2), Ratio::new(33, 100));
+    assert_eq!(round(-2, 3, 2), Ratio::new(-67, 100));
+    assert_eq!(round(1, 2, 0), Ratio::new(1, 1));
+    assert_eq!(round(5, 2, 0), Ratio::new(3, 1));
+    assert_eq!(round(-5, 2, 0), Ratio::new(-3, 1));
+    assert_eq!(round(1, 8, 2), Ratio::new(13, 100));
+    assert_eq!(round(-1, 8, 2), Ratio::new(-13, 100));
+}
+
+#[test]
+fn test_round_overflow() {
+    let kind = |r: Ratio<i32>, places: u32| *round_to_decimals(&r, places).unwrap_err().kind();
+
+    assert_eq!(kind(Ratio::new(1, 3), 10), RatioErrorKind::Overflow);
+    assert_eq!(kind(Ratio::new(i32::MAX, 3), 1), RatioErrorKind::Overflow);
+    // The scale is reduced against the denominator before multiplying.
+    assert_eq!(
+        round_to_decimals(&Ratio::new(i32::MAX, 1000), 3).unwrap(),
+        Ratio::new(i32::MAX, 1000)
+    );
+    assert_eq!(
+        round_to_decimals(&Ratio::<i32>::new(i32::MAX, 2), 0).unwrap(),
+        Ratio::new(1073741824, 1)
+    );
+    assert_eq!(
+        round_to_decimals_half_up(&Ratio::<i32>::new(i32::MAX, 1), 0).unwrap(),
+        Ratio::new(i32::MAX, 1)
+    );
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+
+use num_rational::Ratio;
+use num_rational_parse::FlexRatio;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Config {
+    #[serde(deserialize_with = "num_rational_parse::deserialize_flex")]
+    ratio: Ratio<i32>,
+}
+
+fn ratio(toml: &str) -> Result<Ratio<i32>, toml::de::Error> {
+    toml::from_str::<Config>(toml).map(|c| c.ratio)
+}
+
+#[test]
+fn test_deserialize_toml() {
+    assert_eq!(ratio("ratio = 3").unwrap(), Ratio::new(3, 1));
+    assert_eq!(ratio("ratio = -3").unwrap(), Ratio::new(-3, 1));
+    assert_eq!(ratio("ratio = 0.5").unwrap(), Ratio::new(1, 2));
+    assert_eq!(ratio("ratio = 0.1").unwrap(), Ratio::new(1, 10));
+    assert_eq!(ratio("ratio = -2.5e-3").unwrap(), Ratio::new(-1, 400));
+    assert_eq!(ratio("ratio = 1e9").unwrap(), Ratio::new(1_000_000_000, 1));
+    assert_eq!(ratio("ratio = -0.0").unwrap(), Ratio::new(0, 1));
+    assert_eq!(ratio(r#"ratio = "1/3""#).unwrap(), Ratio::new(1, 3));
+    assert_eq!(
+        ratio(r#"ratio = " 1_000 / 2_000 ""#).unwrap(),
+        Ratio::new(1, 2)
+    );
+    assert_eq!(ratio("ratio = '1.5e-1'").unwrap(), Ratio::new(3, 20));
+}
+
+#[test]
+fn test_deserialize_toml_invalid() {
+    let message = |toml: &str| ratio(toml).unwrap_err().message().to_owned();
+
+    assert_eq!(message("ratio = 2147483648"), "overflow");
+    assert_eq!(message("ratio = 1e10"), "overflow");
+    assert_eq!(message("ratio = 0.1234567890123"), "overflow");
+    assert_eq!(message("ratio = inf"), "failed to parse integer");
+    assert_eq!(message("ratio = nan"), "failed to parse integer");
+    assert_eq!(message(r#"ratio = "1/0""#), "zero value denominator");
+    assert_eq!(message(r#"ratio = "x""#), "failed to parse integer");
+    assert!(message("ratio = true").contains("a rational number"));
+}
+
+#[test]
+fn test_deserialize_flex_ratio() {
+    #[derive(Deserialize)]
+    struct Wrapped {
+        values: Vec<FlexRatio<i64>>,
+    }
+
+    let wrapped: Wrapped = toml::from_str(r#"values = [1, 0.25, "2/3"]"#).unwrap();
+    let values: Vec<Ratio<i64>> = wrapped.values.into_iter().map(Ratio::from).collect();
+    assert_eq!(
+        values,
+        [Ratio::new(1, 1), Ratio::new(1, 4), Ratio::new(2, 3)]
+    );
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "util")]
+
+use num_rational::Ratio;
+use num_rational_parse::{RatioErrorKind, from_kv, try_all_int_types};
+
+#[test]
+fn test_try_all_int_types() {
+    let names = |s: &str| -> Vec<&str> {
+        try_all_int_types!(s)
+            .iter()
+            .filter(|(_, r)| r.is_ok())
+            .map(|(name, _)| *name)
+            .collect()
+    };
+
+    assert_eq!(names("3/4"), ["i8", "i16", "i32", "i64", "i128"]);
+    assert_eq!(names("-32768"), ["i16", "i32", "i64", "i128"]);
+    assert_eq!(names("-32769"), ["i32", "i64", "i128"]);
+    assert_eq!(names("1e-12"), ["i64", "i128"]);
+    assert_eq!(names("1e30"), ["i128"]);
+    assert!(names("1e40").is_empty());
+
+    let results = try_all_int_types!("x");
+    assert!(
+        results
+            .iter()
+            .all(|(_, r)| *r.unwrap_err().kind() == RatioErrorKind::ParseError)
+    );
+}
+
+#[test]
+fn test_from_kv() {
+    let kv = |line: &str, key: &str| from_kv::<i32>(line, key);
+    let line = "ratio=3/4; other = 5 ,scale= -1.5e2";
+
+    assert_eq!(kv(line, "ratio").unwrap(), Ratio::new(3, 4));
+    assert_eq!(kv(line, "other").unwrap(), Ratio::new(5, 1));
+    assert_eq!(kv(line, "scale").unwrap(), Ratio::new(-150, 1));
+    assert_eq!(kv("a=1;a=2", "a").unwrap(), Ratio::new(1, 1));
+    assert_eq!(kv("  a  =  1 / 2  ", "a").unwrap(), Ratio::new(1, 2));
+    assert_eq!(kv("junk; a=1", "a").unwrap(), Ratio::new(1, 1));
+
+    let kind = |line: &str, key: &str| *kv(line, key).unwrap_err().kind();
+    assert_eq!(kind(line, "rat"), RatioErrorKind::KeyNotFound);
+    assert_eq!(kind(line, "Ratio"), RatioErrorKind::KeyNotFound);
+    assert_eq!(kind("", "a"), RatioErrorKind::KeyNotFound);
+    assert_eq!(kind("a", "a"), RatioErrorKind::KeyNotFound);
+    assert_eq!(kind("a=", "a"), RatioErrorKind::ParseError);
+    assert_eq!(kind("a=1=2", "a"), RatioErrorKind::ParseError);
+    assert_eq!(kind("a=1/0", "a"), RatioErrorKind::ZeroDenominator);
+}